@@ -0,0 +1,91 @@
+use anyhow::{bail, Context, Result};
+
+/// Command line arguments for a day's own modes, read one flag at a time.
+///
+/// A flag's value can follow it as the next argument or be given inline as
+/// `--name=value`, and each day decides which of its flags take one.
+pub struct Args {
+    rest: std::vec::IntoIter<String>,
+    /// The flag `next_flag` last returned, with its inline value until it's taken.
+    current: Option<(String, Option<String>)>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        Args { rest: args.into_iter().collect::<Vec<_>>().into_iter(), current: None }
+    }
+
+    /// The name of the next flag, such as `--input` for both `--input <path>`
+    /// and `--input=<path>`. It's an error for the flag before it to have
+    /// been given an inline value it doesn't take.
+    pub fn next_flag(&mut self) -> Result<Option<String>> {
+        if let Some((name, Some(_))) = &self.current {
+            bail!("{} does not take a value", name);
+        }
+        let Some(arg) = self.rest.next() else {
+            self.current = None;
+            return Ok(None);
+        };
+
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with('-') => (name.to_owned(), Some(value.to_owned())),
+            _ => (arg, None),
+        };
+        self.current = Some((name.clone(), inline));
+        Ok(Some(name))
+    }
+
+    /// The value of the current flag, given inline or as the next argument.
+    pub fn value(&mut self) -> Result<String> {
+        let Some((name, inline)) = &mut self.current else {
+            bail!("expected a flag before its value");
+        };
+        match inline.take() {
+            Some(value) => Ok(value),
+            None => self.rest.next().with_context(|| format!("{} requires a value", name)),
+        }
+    }
+
+    /// The current flag's inline value, for flags whose value is optional
+    /// such as `--plot[=spark]`.
+    pub fn inline_value(&mut self) -> Option<String> {
+        self.current.as_mut()?.1.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::new(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn values_inline_or_next() -> Result<()> {
+        let mut args = args(&["--input", "a.txt", "--format=json", "--wide", "--plot=spark", "--plot"]);
+        assert_eq!(args.next_flag()?.as_deref(), Some("--input"));
+        assert_eq!(args.value()?, "a.txt");
+        assert_eq!(args.next_flag()?.as_deref(), Some("--format"));
+        assert_eq!(args.value()?, "json");
+        assert_eq!(args.next_flag()?.as_deref(), Some("--wide"));
+        assert_eq!(args.next_flag()?.as_deref(), Some("--plot"));
+        assert_eq!(args.inline_value().as_deref(), Some("spark"));
+        assert_eq!(args.next_flag()?.as_deref(), Some("--plot"));
+        assert_eq!(args.inline_value(), None);
+        assert_eq!(args.next_flag()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn misplaced_values_are_errors() -> Result<()> {
+        let mut missing = args(&["--input"]);
+        missing.next_flag()?;
+        assert_eq!(missing.value().unwrap_err().to_string(), "--input requires a value");
+
+        let mut unused = args(&["--wide=yes", "--csv"]);
+        unused.next_flag()?;
+        assert_eq!(unused.next_flag().unwrap_err().to_string(), "--wide does not take a value");
+        Ok(())
+    }
+}
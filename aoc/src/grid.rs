@@ -0,0 +1,265 @@
+use crate::{parse, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Point = (usize, usize);
+
+/// Up, down, left and right.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The orthogonal directions plus the four diagonals.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1,  0),          (1,  0),
+    (-1,  1), (0,  1), (1,  1),
+];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    ///
+    /// Panics if the grid has more cells than fit in a `usize`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let cells = width.checked_mul(height).unwrap_or_else(|| panic!("a {}x{} grid is too large", width, height));
+        Grid { width, height, cells: vec![fill; cells] }
+    }
+
+    /// A grid of `cells` laid out row by row, `width` to a row.
+    ///
+    /// Panics if the cells don't fill a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells don't fill rows of {}", cells.len(), width);
+        Grid { width, height: cells.len() / width, cells }
+    }
+
+    /// Parses a grid with one cell per character. `cell` converts each
+    /// character and returns `None` for anything that isn't `expected`.
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse::lines(input, |line| {
+            let row = line.char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| ParseError::new(line, &line[i..i + c.len_utf8()], expected)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                Some(width) if width != row.len() => {
+                    Err(ParseError::new(line, line, format!("a row of {} cells", width)))
+                }
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The points next to `point` in each of `directions` that are inside the grid.
+    pub fn neighbors<'a>(
+        &self,
+        (x, y): Point,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        let (width, height) = (self.width, self.height);
+        directions.iter().filter_map(move |(dx, dy)| {
+            let x = x.checked_add_signed(*dx).filter(|x| *x < width)?;
+            let y = y.checked_add_signed(*dy).filter(|y| *y < height)?;
+            Some((x, y))
+        })
+    }
+
+    /// The up to 4 orthogonal neighbors of `point`.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, &ORTHOGONAL)
+    }
+
+    /// The up to 8 neighbors of `point`, including diagonals.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, &ALL_DIRECTIONS)
+    }
+
+    /// Renders each cell with `render`, one row per line.
+    pub fn display_with<'a, F, D>(&'a self, render: F) -> impl fmt::Display + 'a
+    where
+        F: Fn(&T) -> D + 'a,
+        D: fmt::Display,
+    {
+        DisplayWith { grid: self, render }
+    }
+
+    fn fmt_cells(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        mut write_cell: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+    ) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write_cell(f, cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a map of single digits, such as day 9's heights.
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, "a character", Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).unwrap_or_else(|| panic!("{:?} is outside the grid", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_cells(f, |f, cell| write!(f, "{}", cell))
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+}
+
+impl<T, F, D> fmt::Display for DisplayWith<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.fmt_cells(f, |f, cell| write!(f, "{}", (self.render)(cell)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((2, 1)).collect::<Vec<_>>(), vec![(1, 0), (2, 0), (1, 1)]);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn rows_and_columns() -> Result<(), ParseError> {
+        let grid = Grid::parse_digits("123\n456")?;
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456");
+        Ok(())
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let error = Grid::parse_chars("#.#\n##\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "a row of 3 cells"));
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, ErrorKind, Read},
+};
+
+/// Path that means "read the puzzle input from stdin".
+pub const STDIN: &str = "-";
+
+/// Reads puzzle input from `path`, or from stdin if `path` is `-`.
+///
+/// Missing and empty inputs are reported as errors up front rather than
+/// surfacing later as a confusing parse failure.
+pub fn read_input(path: &str) -> Result<String> {
+    let input = if path == STDIN {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).context("failed to read input from stdin")?;
+        input
+    } else {
+        match fs::read_to_string(path) {
+            Ok(input) => input,
+            Err(e) if e.kind() == ErrorKind::NotFound => bail!("input file {} does not exist", path),
+            Err(e) => return Err(e).with_context(|| format!("failed to read input file {}", path)),
+        }
+    };
+
+    if input.trim().is_empty() {
+        return Err(empty_input(path));
+    }
+
+    Ok(input)
+}
+
+/// Opens puzzle input at `path`, or stdin if `path` is `-`, to be read a line
+/// at a time.
+///
+/// A missing file is reported just like `read_input` does. Whether the input
+/// is empty isn't known until reading starts, so callers report that with
+/// `empty_input` once the first line turns out to be missing.
+pub fn open_input(path: &str) -> Result<Box<dyn BufRead>> {
+    if path == STDIN {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    match File::open(path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(e) if e.kind() == ErrorKind::NotFound => bail!("input file {} does not exist", path),
+        Err(e) => Err(e).with_context(|| format!("failed to read input file {}", path)),
+    }
+}
+
+/// The error for an input at `path` with nothing in it.
+pub fn empty_input(path: &str) -> anyhow::Error {
+    let name = if path == STDIN { "stdin" } else { path };
+    anyhow!("input {} is empty", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_and_empty_inputs_read_the_same_either_way() {
+        let missing = "no/such/input.txt";
+        assert_eq!(read_input(missing).unwrap_err().to_string(), "input file no/such/input.txt does not exist");
+        assert_eq!(open_input(missing).err().unwrap().to_string(), "input file no/such/input.txt does not exist");
+        assert_eq!(empty_input(STDIN).to_string(), "input stdin is empty");
+    }
+}
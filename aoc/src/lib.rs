@@ -0,0 +1,153 @@
+use anyhow::{bail, Context, Result};
+use std::{env, fmt::Display, process};
+
+mod args;
+pub mod grid;
+mod input;
+pub mod output;
+pub mod parse;
+mod solution;
+
+pub use args::Args;
+pub use grid::Grid;
+pub use input::{empty_input, open_input, read_input, STDIN};
+pub use output::Format;
+pub use parse::ParseError;
+pub use solution::{Answer, Part, Report, Solution};
+
+/// A single day's puzzle.
+///
+/// Every day parses its input once into `Input` and then solves both parts
+/// from a shared reference to it, so runners, benchmarks and tests can drive
+/// all days the same way.
+pub trait Puzzle {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    /// Parsed form of the puzzle input. It may borrow from the raw input.
+    type Input<'a>;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Output1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Output2>;
+}
+
+/// Entry point for a day's binary.
+///
+/// Accepts `--input <path>` (or `-` for stdin) and otherwise falls back to
+/// `default_input`, which is usually the day's own `input.txt`, and
+/// `--format json` to print `output::Record`s instead of text. Errors are
+/// printed with `report` and exit the process.
+pub fn main<P: Puzzle>(default_input: &str) {
+    let mut path = default_input.to_owned();
+    if let Err(e) = try_main::<P>(&mut path) {
+        eprintln!("{}", report(&e, &path));
+        process::exit(1);
+    }
+}
+
+/// Entry point for a day's binary that has modes of its own.
+///
+/// When any of `modes` is given, `run` handles every argument itself and
+/// reads whichever input it needs. Its errors are printed with `report`
+/// against the path it last set, which starts as `default_input`. Without any
+/// of them the day runs like every other day with `main`. `--help` always
+/// wins, printing the usual usage followed by the day's own `usage`.
+pub fn main_with_modes<P: Puzzle>(
+    default_input: &str,
+    modes: &[&str],
+    usage: &str,
+    run: impl FnOnce(&mut Args, &mut String) -> Result<()>,
+) {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let given = |flag: &str| {
+        args.iter().any(|a| a.strip_prefix(flag).is_some_and(|rest| rest.is_empty() || rest.starts_with('=')))
+    };
+
+    if given("--help") || given("-h") {
+        print_help::<P>();
+        println!("{}", usage);
+    } else if modes.iter().any(|mode| given(mode)) {
+        let mut path = default_input.to_owned();
+        if let Err(e) = run(&mut Args::new(args), &mut path) {
+            eprintln!("{}", report(&e, &path));
+            process::exit(1);
+        }
+    } else {
+        main::<P>(default_input);
+    }
+}
+
+fn print_help<P: Puzzle>() {
+    println!("Day {}: {}", P::DAY, P::TITLE);
+    println!("usage: day{:02} [--input <path|->] [--format text|json]", P::DAY);
+}
+
+fn try_main<P: Puzzle>(path: &mut String) -> Result<()> {
+    let mut format = Format::Text;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => *path = args.next().context("--input requires a path")?,
+            "--format" | "-f" => format = args.next().context("--format requires text or json")?.parse()?,
+            "--help" | "-h" => {
+                print_help::<P>();
+                return Ok(());
+            }
+            _ => match (arg.strip_prefix("--input="), arg.strip_prefix("--format=")) {
+                (Some(p), _) => *path = p.to_owned(),
+                (_, Some(f)) => format = f.parse()?,
+                _ => bail!("unexpected argument {}", arg),
+            },
+        }
+    }
+
+    match format {
+        Format::Text => run::<P>(&read_input(path)?),
+        Format::Json => run_json::<P>(path),
+    }
+}
+
+// Every failure ends up in a record, so only the exit code reports it.
+fn run_json<P: Puzzle>(path: &str) -> Result<()> {
+    let report = read_input(path).and_then(|input| solution::solve::<P>(&input, &Part::ALL));
+    let records = output::records(P::DAY, &report, &Part::ALL);
+    println!("{}", output::to_json(&records));
+
+    let failures = records.iter().filter(|r| r.error.is_some()).count();
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
+
+/// Renders `error` for the terminal. Parse errors are drawn as a diagram
+/// pointing into the input read from `path`.
+pub fn report(error: &anyhow::Error, path: &str) -> String {
+    match error.downcast_ref::<ParseError>() {
+        Some(e) if path == STDIN => e.diagram("<stdin>"),
+        Some(e) => e.diagram(path),
+        None => format!("error: {:#}", error),
+    }
+}
+
+/// Parses `input` and prints the answers to both parts.
+pub fn run<P: Puzzle>(input: &str) -> Result<()> {
+    let input = P::parse(input)?;
+
+    print_answer(1, P::part1(&input)?);
+    print_answer(2, P::part2(&input)?);
+    Ok(())
+}
+
+fn print_answer(part: u8, answer: impl Display) {
+    let answer = answer.to_string();
+    // Some answers (like day 13's folded paper) are pictures, start them on their own line.
+    if answer.contains('\n') {
+        println!("part {}:\n{}", part, answer);
+    } else {
+        println!("part {}: {}", part, answer);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{Answer, Part, Report};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// How answers are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One `Record` per part, for scripts rather than people.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("invalid format {}, expected text or json", s),
+        }
+    }
+}
+
+/// The outcome of one part in `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Value>,
+    pub elapsed_ms: Option<f64>,
+    pub error: Option<String>,
+}
+
+/// An answer as JSON. Pictures, like day 13's folded paper, are split into
+/// their lines instead of being one string full of `\n`s.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Value {
+    Text(String),
+    Lines(Vec<String>),
+}
+
+impl From<&str> for Value {
+    fn from(answer: &str) -> Self {
+        if answer.contains('\n') {
+            Value::Lines(answer.lines().map(str::to_owned).collect())
+        } else {
+            Value::Text(answer.to_owned())
+        }
+    }
+}
+
+impl Record {
+    pub fn new(day: u8, answer: &Answer) -> Self {
+        let (value, error) = match &answer.result {
+            Ok(text) => (Some(Value::from(text.as_str())), None),
+            Err(e) => (None, Some(format!("{:#}", e))),
+        };
+
+        Record {
+            day,
+            part: part_number(answer.part),
+            answer: value,
+            elapsed_ms: Some(answer.elapsed.as_secs_f64() * 1000.0),
+            error,
+        }
+    }
+
+    /// A part that never ran because the day as a whole failed, e.g. its
+    /// input didn't parse.
+    pub fn failed(day: u8, part: Part, error: &anyhow::Error) -> Self {
+        Record {
+            day,
+            part: part_number(part),
+            answer: None,
+            elapsed_ms: None,
+            error: Some(format!("{:#}", error)),
+        }
+    }
+}
+
+/// One record for each of `parts`, whether or not the day could be solved.
+pub fn records(day: u8, report: &Result<Report>, parts: &[Part]) -> Vec<Record> {
+    match report {
+        Ok(report) => report.answers.iter().map(|answer| Record::new(day, answer)).collect(),
+        Err(e) => parts.iter().map(|&part| Record::failed(day, part, e)).collect(),
+    }
+}
+
+/// Renders `records` as a pretty-printed JSON array.
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records always serialize")
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use std::time::Duration;
+
+    #[test]
+    fn pictures_become_lines() -> Result<()> {
+        let answers = vec![
+            Answer { part: Part::One, result: Ok("17".to_owned()), elapsed: Duration::from_millis(2) },
+            Answer { part: Part::Two, result: Ok("##\n# ".to_owned()), elapsed: Duration::ZERO },
+        ];
+        let report = Ok(Report { parse_time: Duration::ZERO, answers });
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records(13, &report, &Part::ALL)))?;
+
+        assert_eq!(json[0]["answer"], "17");
+        assert_eq!(json[0]["elapsed_ms"], 2.0);
+        assert_eq!(json[1]["answer"], serde_json::json!(["##", "# "]));
+        assert_eq!(json[1]["error"], serde_json::Value::Null);
+        Ok(())
+    }
+
+    #[test]
+    fn failed_days_still_have_a_record_per_part() {
+        let records = records(4, &Err(anyhow!("no boards")), &Part::ALL);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].part, 2);
+        assert_eq!(records[1].error.as_deref(), Some("no boards"));
+        assert_eq!(records[1].answer, None);
+    }
+}
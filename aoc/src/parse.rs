@@ -0,0 +1,163 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An error in the puzzle input, pointing at the offending text.
+///
+/// Parsers report errors relative to the `&str` they were handed. Callers that
+/// hand out pieces of a larger input move the error to its place in that input
+/// with `rebase`, so the final error carries the real line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// How many characters are at fault, at least 1.
+    pub len: usize,
+    /// The whole line the error is on.
+    pub snippet: String,
+    /// What the parser wanted to see instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `at`, which must be a subslice of `source`.
+    pub fn new(source: &str, at: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_of(source, at);
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..].find('\n').map_or(source.len(), |i| offset + i);
+        let snippet = source[line_start..line_end].trim_end_matches('\r');
+
+        let column = source[line_start..offset].chars().count() + 1;
+        let at_len = at.lines().next().unwrap_or("").chars().count();
+
+        ParseError {
+            line: source[..offset].matches('\n').count() + 1,
+            column,
+            len: at_len.clamp(1, (snippet.chars().count() + 1).saturating_sub(column).max(1)),
+            snippet: snippet.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves an error found while parsing `inner` to where it is in `outer`.
+    /// `inner` must be a subslice of `outer`.
+    pub fn rebase(self, outer: &str, inner: &str) -> Self {
+        let offset = offset_of(outer, inner);
+        let base = ParseError::new(outer, &outer[offset..offset], String::new());
+
+        let line = base.line + self.line - 1;
+        let column = if self.line == 1 { base.column + self.column - 1 } else { self.column };
+        let snippet = outer.lines().nth(line - 1).unwrap_or(&self.snippet).to_owned();
+
+        ParseError { line, column, snippet, ..self }
+    }
+
+    /// The text the error points at.
+    pub fn found(&self) -> String {
+        self.snippet.chars().skip(self.column - 1).take(self.len).collect()
+    }
+
+    /// Renders the error like a compiler diagnostic, with a caret under the
+    /// offending text. `path` names the input in the location line.
+    pub fn diagram(&self, path: &str) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "error: expected {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.expected,
+            gutter,
+            path,
+            self.line,
+            self.column,
+            gutter,
+            self.line,
+            self.snippet,
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        let found = self.found();
+        if !found.is_empty() {
+            write!(f, ", found `{}`", found)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+fn offset_of(source: &str, at: &str) -> usize {
+    let offset = (at.as_ptr() as usize).wrapping_sub(source.as_ptr() as usize);
+    assert!(offset <= source.len(), "`at` must be a subslice of `source`");
+    offset
+}
+
+/// Parses every line of `input`, attributing errors to the line they occur on.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input.lines().map(|line| parse(line).map_err(|e| e.rebase(input, line))).collect()
+}
+
+/// Like `str::split_once`, but reports a missing delimiter.
+pub fn split_once<'a>(source: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    source.split_once(delimiter).ok_or_else(|| {
+        let expected = match delimiter.trim_matches(' ') {
+            "" => "a space".to_owned(),
+            "\n\n" => "a blank line".to_owned(),
+            delimiter => format!("`{}`", delimiter),
+        };
+        ParseError::new(source, source, expected)
+    })
+}
+
+/// Parses `at`, a subslice of `source`, as a number.
+pub fn number<T: FromStr>(source: &str, at: &str) -> Result<T, ParseError> {
+    at.parse().map_err(|_| ParseError::new(source, at, "a number"))
+}
+
+/// Parses a comma-separated list of numbers, such as day 6's fish.
+pub fn comma_separated<T: FromStr>(source: &str) -> Result<Vec<T>, ParseError> {
+    source.trim_end().split(',').map(|n| number(source, n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_their_line_and_column() {
+        let input = "0,9 -> 5,9\n8,0 => 0,8\n";
+        let error = lines(input, |line| split_once(line, " -> ")).unwrap_err();
+        assert_eq!((error.line, error.column, error.len), (2, 1, 10));
+        assert_eq!(error.snippet, "8,0 => 0,8");
+        assert_eq!(error.expected, "`->`");
+    }
+
+    #[test]
+    fn rebase_offsets_the_column_on_the_first_line() {
+        let input = "header\nforward x5";
+        let line = &input[7..];
+        let (_, amount) = split_once(line, " ").unwrap();
+        let error = number::<u32>(line, amount).unwrap_err().rebase(input, line);
+        assert_eq!((error.line, error.column), (2, 9));
+        assert_eq!(error.found(), "x5");
+    }
+
+    #[test]
+    fn diagram_underlines_the_offending_text() {
+        let error = comma_separated::<u32>("3,4,x,1").unwrap_err();
+        assert_eq!(error.diagram("input.txt"), "\
+error: expected a number
+ --> input.txt:1:5
+  |
+1 | 3,4,x,1
+  |     ^");
+    }
+}
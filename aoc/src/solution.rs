@@ -0,0 +1,113 @@
+use crate::Puzzle;
+use anyhow::{bail, Result};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("invalid part {}, expected 1 or 2", s),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, rendered as text.
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    pub result: Result<String>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of solving a day, with the time spent parsing its input.
+#[derive(Debug)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+    }
+}
+
+/// A `Puzzle` with its types erased, so every day can be stored in one list
+/// and run the same way.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: u8,
+    pub title: &'static str,
+    /// The input that ships with the day.
+    pub input_path: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Report>,
+}
+
+impl Solution {
+    pub fn new<P: Puzzle>(input_path: &'static str) -> Self {
+        Solution {
+            day: P::DAY,
+            title: P::TITLE,
+            input_path,
+            solve: solve::<P>,
+        }
+    }
+
+    /// Parses `input` and solves each of `parts`.
+    ///
+    /// A parse failure fails the whole day, while a failing part is reported
+    /// in its `Answer` so the other part still runs.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Report> {
+        (self.solve)(input, parts)
+    }
+}
+
+pub(crate) fn solve<P: Puzzle>(input: &str, parts: &[Part]) -> Result<Report> {
+    let start = Instant::now();
+    let input = P::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts.iter().map(|&part| {
+        let (result, elapsed) = match part {
+            Part::One => timed(|| P::part1(&input)),
+            Part::Two => timed(|| P::part2(&input)),
+        };
+        Answer { part, result, elapsed }
+    }).collect();
+
+    Ok(Report { parse_time, answers })
+}
+
+// Only solving the part is timed, rendering the answer is not.
+fn timed<T: Display>(solve: impl FnOnce() -> Result<T>) -> (Result<String>, Duration) {
+    let start = Instant::now();
+    let result = solve();
+    let elapsed = start.elapsed();
+    (result.map(|answer| answer.to_string()), elapsed)
+}
@@ -0,0 +1,167 @@
+use anyhow::{Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+use std::{collections::VecDeque, io::BufRead};
+
+pub mod plot;
+mod profile;
+
+pub use profile::{Profile, Run, Step};
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+
+    type Input<'a> = Vec<isize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<isize>> {
+        let depths = parse::lines(input, |line| parse::number(line, line))?;
+        Ok(depths)
+    }
+
+    fn part1(depths: &Vec<isize>) -> Result<usize> {
+        Ok(count_increases(depths.iter().copied(), 1))
+    }
+
+    fn part2(depths: &Vec<isize>) -> Result<usize> {
+        Ok(count_increases(depths.iter().copied(), 3))
+    }
+}
+
+/// Counts how often the sum of a sliding window of `size` readings is larger
+/// than the sum of the window before it.
+///
+/// Only the last `size` readings are kept, so `readings` can be arbitrarily long.
+pub fn count_increases(readings: impl IntoIterator<Item = isize>, size: usize) -> usize {
+    let mut counter = IncreaseCounter::new(size);
+    for reading in readings {
+        counter.push(reading);
+    }
+    counter.increases()
+}
+
+/// Counts window increases one reading at a time.
+///
+/// Neighbouring windows share all but one reading at each end, so a window's
+/// sum is larger than the last one's exactly when the reading entering it is
+/// larger than the one leaving it. No sums are needed and nothing can overflow.
+#[derive(Debug, Clone)]
+pub struct IncreaseCounter {
+    window: VecDeque<isize>,
+    size: usize,
+    increases: usize,
+}
+
+impl IncreaseCounter {
+    /// Panics if `size` is 0.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "window size must be at least 1");
+        IncreaseCounter { window: VecDeque::with_capacity(size), size, increases: 0 }
+    }
+
+    pub fn push(&mut self, reading: isize) {
+        if self.window.len() == self.size {
+            let leaving = self.window.pop_front();
+            if leaving.is_some_and(|leaving| reading > leaving) {
+                self.increases += 1;
+            }
+        }
+        self.window.push_back(reading);
+    }
+
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+}
+
+/// Reads one depth per line from `reader` without reading all of it up front.
+///
+/// Parse errors carry the line number they were found on.
+pub fn read_depths(reader: impl BufRead) -> impl Iterator<Item = Result<isize>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let line = line.with_context(|| format!("failed to read line {}", i + 1))?;
+        let depth = parse::number(&line, line.trim_end()).map_err(|e| ParseError { line: i + 1, ..e })?;
+        Ok(depth)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day01::parse(EXAMPLE)?;
+        assert_eq!(Day01::part1(&input)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day01::parse(EXAMPLE)?;
+        assert_eq!(Day01::part2(&input)?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn any_window_size() -> Result<()> {
+        let depths = read_depths(EXAMPLE.as_bytes()).collect::<Result<Vec<_>>>()?;
+        let counts = (1..=5).map(|size| count_increases(depths.iter().copied(), size)).collect::<Vec<_>>();
+        assert_eq!(counts, vec![7, 5, 5, 6, 5]);
+        assert_eq!(count_increases(depths.iter().copied(), depths.len()), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn example_profile() -> Result<()> {
+        let profile = Profile::new(&Day01::parse(EXAMPLE)?)?;
+        assert_eq!((profile.min, profile.max, profile.mean), (199, 269, 225.6));
+        assert_eq!(profile.longest_increase, Some(Run { start: 0, len: 4 }));
+        assert_eq!(profile.longest_decrease, Some(Run { start: 3, len: 2 }));
+        assert_eq!(profile.largest_jump, Some(Step { index: 6, change: 33 }));
+        assert_eq!(profile.largest_drop, Some(Step { index: 4, change: -10 }));
+        assert!(profile.plateaus.is_empty());
+
+        let profile = Profile::new(&[1, 1, 2, 2, 2, 3])?;
+        assert_eq!(profile.plateaus, vec![Run { start: 0, len: 2 }, Run { start: 2, len: 3 }]);
+        assert_eq!(profile.longest_plateau(), Some(Run { start: 2, len: 3 }));
+
+        let profile = Profile::new(&[isize::MAX, isize::MIN, isize::MAX])?;
+        assert_eq!(profile.largest_drop, Some(Step { index: 1, change: isize::MIN as i128 - isize::MAX as i128 }));
+        assert_eq!(profile.largest_jump, Some(Step { index: 2, change: isize::MAX as i128 - isize::MIN as i128 }));
+        Ok(())
+    }
+
+    #[test]
+    fn plots_highlight_increasing_windows() -> Result<()> {
+        let depths = Day01::parse(EXAMPLE)?;
+        for size in [1, 3] {
+            let increasing = plot::increasing_windows(&depths, size);
+            assert_eq!(increasing.iter().filter(|i| **i).count(), count_increases(depths.iter().copied(), size));
+        }
+
+        let increasing = plot::increasing_windows(&depths, 1);
+        assert_eq!(plot::sparkline(&depths, &increasing, 80), "▁▁▂▂▁▂▅█▇▇\n ^^^ ^^^ ^");
+        assert_eq!(plot::sparkline(&depths, &increasing, 5), "▁▂▁▇█\n ^ ^");
+
+        // Squeezed into 5 columns no column's mean reaches 269 or 199, but the labels still do.
+        let chart = plot::chart(&depths, &increasing, 5, 4);
+        let labels = chart.lines().map(|l| l.split('|').next().unwrap().trim()).collect::<Vec<_>>();
+        assert_eq!(labels[..4], ["269", "", "", "199"]);
+        Ok(())
+    }
+
+    #[test]
+    fn streamed_errors_name_their_line() {
+        let error = read_depths("199\n200\n2o8\n".as_bytes()).find_map(Result::err).unwrap();
+        assert_eq!(error.downcast_ref::<ParseError>().map(|e| e.line), Some(3));
+    }
+}
@@ -1,23 +1,119 @@
-use anyhow::Result;
-
-const INPUT: &'static str = include_str!("../input.txt");
-
-fn main() -> Result<()> {
-    let depths = INPUT.lines()
-        .map(|line| line.parse::<isize>())
-        .collect::<Result<Vec<_>, _>>()?;
-    
-    println!("Part 1: {}", part1(&depths));
-    println!("Part 2: {}", part2(&depths));
-    
+use anyhow::{bail, Context, Result};
+use aoc::{Args, Format, Puzzle};
+use day01::{plot, read_depths, Day01, IncreaseCounter, Profile, INPUT_PATH};
+use std::fs;
+
+const USAGE: &str = "       day01 --window <size> [--input <path|->]
+       day01 --report [--format text|json] [--input <path|->]
+       day01 [--plot[=chart|spark]] [--svg <path>] [--window <size>] [--input <path|->]";
+
+const MODES: &[&str] = &["--window", "-w", "--report", "--plot", "--svg"];
+
+const PLOT_WIDTH: usize = 80;
+const PLOT_HEIGHT: usize = 20;
+
+#[derive(Clone, Copy)]
+enum Plot {
+    Chart,
+    Sparkline,
+}
+
+fn main() {
+    aoc::main_with_modes::<Day01>(INPUT_PATH, MODES, USAGE, try_main);
+}
+
+fn try_main(args: &mut Args, path: &mut String) -> Result<()> {
+    let mut window = None;
+    let mut report = false;
+    let mut format = Format::Text;
+    let mut plot = None;
+    let mut svg = None;
+
+    while let Some(name) = args.next_flag()? {
+        match name.as_str() {
+            "--report" => report = true,
+            "--plot" => {
+                plot = match args.inline_value().as_deref() {
+                    None | Some("chart") => Some(Plot::Chart),
+                    Some("spark") => Some(Plot::Sparkline),
+                    Some(style) => bail!("invalid plot {}, expected chart or spark", style),
+                };
+            }
+            "--window" | "-w" => {
+                let value = args.value()?;
+                window = Some(value.parse::<usize>().ok().filter(|s| *s > 0).with_context(|| {
+                    format!("invalid window size {}, expected a positive number", value)
+                })?);
+            }
+            "--format" | "-f" => format = args.value()?.parse()?,
+            "--svg" => svg = Some(args.value()?),
+            "--input" | "-i" => *path = args.value()?,
+            _ => bail!("unexpected argument {}", name),
+        }
+    }
+
+    if format == Format::Json && !report {
+        bail!("--format json only works with --report");
+    }
+
+    if plot.is_some() || svg.is_some() {
+        if report {
+            bail!("--report cannot be combined with --plot or --svg");
+        }
+        // Plots highlight part 1's windows unless told otherwise.
+        return draw(path, window.unwrap_or(1), plot, svg.as_deref());
+    }
+
+    match (window, report) {
+        (Some(size), false) => stream(size, path),
+        (None, true) => print_report(path, format),
+        _ => bail!("--window and --report cannot be combined"),
+    }
+}
+
+/// Counts increasing windows of `size` readings without reading the whole
+/// input first, so it works on inputs too big to fit in memory.
+fn stream(size: usize, path: &str) -> Result<()> {
+    let mut depths = read_depths(aoc::open_input(path)?).peekable();
+    if depths.peek().is_none() {
+        return Err(aoc::empty_input(path));
+    }
+
+    let mut counter = IncreaseCounter::new(size);
+    for depth in depths {
+        counter.push(depth?);
+    }
+
+    println!("{}", counter.increases());
     Ok(())
 }
 
-fn part1(input: &[isize]) -> isize {
-    input.windows(2).map(|w| if w[1] > w[0] { 1 } else { 0 }).sum()
+fn print_report(path: &str, format: Format) -> Result<()> {
+    let input = aoc::read_input(path)?;
+    let profile = Profile::new(&Day01::parse(&input)?)?;
+
+    match format {
+        Format::Text => println!("{}", profile),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&profile)?),
+    }
+    Ok(())
 }
 
-fn part2(input: &[isize]) -> isize {
-    let window_sums = input.windows(3).map(|w| w.iter().sum()).collect::<Vec<isize>>();
-    window_sums.windows(2).map(|w| if w[1] > w[0] { 1 } else { 0 }).sum()
+/// Plots the depths, highlighting the windows of `size` readings that increase.
+fn draw(path: &str, size: usize, style: Option<Plot>, svg: Option<&str>) -> Result<()> {
+    let input = aoc::read_input(path)?;
+    let depths = Day01::parse(&input)?;
+    let increasing = plot::increasing_windows(&depths, size);
+
+    match style {
+        Some(Plot::Chart) => println!("{}", plot::chart(&depths, &increasing, PLOT_WIDTH, PLOT_HEIGHT)),
+        Some(Plot::Sparkline) => println!("{}", plot::sparkline(&depths, &increasing, PLOT_WIDTH)),
+        None => {}
+    }
+
+    if let Some(svg_path) = svg {
+        fs::write(svg_path, plot::svg(&depths, &increasing))
+            .with_context(|| format!("failed to write {}", svg_path))?;
+    }
+    Ok(())
 }
@@ -0,0 +1,160 @@
+use std::{fmt::Write, ops::Range};
+
+/// Which readings complete a window of `size` readings whose sum is larger
+/// than the window before it. These are the windows `count_increases` counts.
+pub fn increasing_windows(depths: &[isize], size: usize) -> Vec<bool> {
+    (0..depths.len()).map(|i| i >= size && depths[i] > depths[i - size]).collect()
+}
+
+/// A one-line chart of `depths` squeezed into at most `width` characters,
+/// with a `^` under each column where most windows increase.
+pub fn sparkline(depths: &[isize], increasing: &[bool], width: usize) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let columns = columns(depths, increasing, width);
+    let scale = Scale::of_columns(&columns);
+    let bars = columns.iter().map(|c| BARS[scale.level(c.depth, BARS.len())]).collect::<String>();
+    let marks = columns.iter().map(|c| if c.increasing { '^' } else { ' ' }).collect::<String>();
+
+    format!("{}\n{}", bars, marks.trim_end())
+}
+
+/// A chart of `depths` `height` rows tall and at most `width` columns wide,
+/// labelled with the smallest and largest depth. Columns where most windows
+/// increase are drawn with `#`, the rest with `.`.
+///
+/// A column squeezes several readings into their mean, so the rows are scaled
+/// to the readings themselves rather than the columns. That keeps the labels
+/// true to the data even when no single column reaches them.
+pub fn chart(depths: &[isize], increasing: &[bool], width: usize, height: usize) -> String {
+    let columns = columns(depths, increasing, width);
+    let scale = Scale::of_depths(depths);
+
+    let mut rows = vec![vec![' '; columns.len()]; height];
+    for (x, column) in columns.iter().enumerate() {
+        let y = height - 1 - scale.level(column.depth, height);
+        rows[y][x] = if column.increasing { '#' } else { '.' };
+    }
+
+    let (top, bottom) = (scale.max.round().to_string(), scale.min.round().to_string());
+    let gutter = top.len().max(bottom.len());
+
+    let mut chart = String::new();
+    for (y, row) in rows.iter().enumerate() {
+        let label = match y {
+            0 => top.as_str(),
+            _ if y == height - 1 => bottom.as_str(),
+            _ => "",
+        };
+        let row = row.iter().collect::<String>();
+        writeln!(chart, "{:>gutter$} |{}", label, row.trim_end(), gutter = gutter).unwrap();
+    }
+    write!(chart, "{:>gutter$}  # increasing  . not increasing", "", gutter = gutter).unwrap();
+    chart
+}
+
+/// A line chart of `depths` as an SVG image, with the readings that complete
+/// an increasing window drawn in red.
+pub fn svg(depths: &[isize], increasing: &[bool]) -> String {
+    const WIDTH: f64 = 800.0;
+    const HEIGHT: f64 = 300.0;
+    const MARGIN: f64 = 40.0;
+
+    let min = depths.iter().min().copied().unwrap_or(0) as f64;
+    let max = depths.iter().max().copied().unwrap_or(0) as f64;
+    let step = (WIDTH - 2.0 * MARGIN) / depths.len().saturating_sub(1).max(1) as f64;
+    let point = |i: usize| {
+        let y = match max - min {
+            range if range > 0.0 => MARGIN + (max - depths[i] as f64) / range * (HEIGHT - 2.0 * MARGIN),
+            _ => HEIGHT / 2.0,
+        };
+        format!("{:.1},{:.1}", MARGIN + i as f64 * step, y)
+    };
+    let polyline = |points: Range<usize>, style: &str| {
+        let points = points.map(point).collect::<Vec<_>>().join(" ");
+        format!("  <polyline fill=\"none\" {} points=\"{}\"/>\n", style, points)
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n  \
+         <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+        w = WIDTH,
+        h = HEIGHT,
+    );
+    svg += &polyline(0..depths.len(), "stroke=\"#999\" stroke-width=\"1\"");
+
+    // Each increasing reading highlights the segment leading up to it, and
+    // neighbouring segments are joined into one line.
+    let mut i = 0;
+    while i < depths.len() {
+        if increasing[i] {
+            let start = i.saturating_sub(1);
+            while i < depths.len() && increasing[i] {
+                i += 1;
+            }
+            svg += &polyline(start..i, "stroke=\"#d62728\" stroke-width=\"2\"");
+        } else {
+            i += 1;
+        }
+    }
+
+    writeln!(svg, "  <text x=\"4\" y=\"{:.1}\" font-size=\"12\">{}</text>", MARGIN + 4.0, max).unwrap();
+    writeln!(svg, "  <text x=\"4\" y=\"{:.1}\" font-size=\"12\">{}</text>", HEIGHT - MARGIN, min).unwrap();
+    svg += "</svg>\n";
+    svg
+}
+
+/// The readings drawn in one column of a chart.
+struct Column {
+    depth: f64,
+    increasing: bool,
+}
+
+/// Groups `depths` into at most `width` columns of about equal size, each
+/// drawn at its mean depth.
+fn columns(depths: &[isize], increasing: &[bool], width: usize) -> Vec<Column> {
+    let count = depths.len().min(width);
+    (0..count)
+        .map(|c| {
+            let range = c * depths.len() / count..(c + 1) * depths.len() / count;
+            let len = range.len();
+            Column {
+                depth: depths[range.clone()].iter().map(|d| *d as f64).sum::<f64>() / len as f64,
+                increasing: increasing[range].iter().filter(|i| **i).count() * 2 > len,
+            }
+        })
+        .collect()
+}
+
+struct Scale {
+    min: f64,
+    max: f64,
+}
+
+impl Scale {
+    /// From the lowest to the highest column, for the most contrast.
+    fn of_columns(columns: &[Column]) -> Self {
+        Scale::spanning(columns.iter().map(|c| c.depth))
+    }
+
+    /// From the smallest to the largest reading.
+    fn of_depths(depths: &[isize]) -> Self {
+        Scale::spanning(depths.iter().map(|d| *d as f64))
+    }
+
+    fn spanning(depths: impl Iterator<Item = f64> + Clone) -> Self {
+        Scale {
+            min: depths.clone().fold(f64::INFINITY, f64::min),
+            max: depths.fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+
+    /// Which of `levels` levels `depth` falls on, from 0 for the smallest.
+    fn level(&self, depth: f64, levels: usize) -> usize {
+        if self.max > self.min {
+            ((depth - self.min) / (self.max - self.min) * (levels - 1) as f64).round() as usize
+        } else {
+            0
+        }
+    }
+}
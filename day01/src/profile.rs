@@ -0,0 +1,123 @@
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::fmt;
+
+/// A stretch of consecutive readings, such as a run of increasing depths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Run {
+    /// Index of the first reading.
+    pub start: usize,
+    /// Number of readings, at least 2.
+    pub len: usize,
+}
+
+/// The change from the reading before `index` to the one at it. It's wider
+/// than the readings, since the gap between two of them might not fit in one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Step {
+    pub index: usize,
+    pub change: i128,
+}
+
+/// Summary statistics of a list of depths, for checking sensor data by eye.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Profile {
+    pub readings: usize,
+    pub min: isize,
+    pub max: isize,
+    pub mean: f64,
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    pub largest_jump: Option<Step>,
+    pub largest_drop: Option<Step>,
+    /// Every run of two or more equal readings.
+    pub plateaus: Vec<Run>,
+}
+
+impl Profile {
+    pub fn new(depths: &[isize]) -> Result<Profile> {
+        let (min, max) = match (depths.iter().min(), depths.iter().max()) {
+            (Some(min), Some(max)) => (*min, *max),
+            _ => bail!("cannot profile an empty list of depths"),
+        };
+        let mean = depths.iter().map(|d| *d as f64).sum::<f64>() / depths.len() as f64;
+
+        let steps = depths.windows(2).enumerate().map(|(i, w)| Step { index: i + 1, change: w[1] as i128 - w[0] as i128 });
+
+        Ok(Profile {
+            readings: depths.len(),
+            min,
+            max,
+            mean,
+            longest_increase: longest(runs(depths, |a, b| b > a)),
+            longest_decrease: longest(runs(depths, |a, b| b < a)),
+            largest_jump: steps.clone().filter(|s| s.change > 0).reduce(|a, b| if b.change > a.change { b } else { a }),
+            largest_drop: steps.filter(|s| s.change < 0).reduce(|a, b| if b.change < a.change { b } else { a }),
+            plateaus: runs(depths, |a, b| b == a),
+        })
+    }
+
+    /// The longest plateau, or the first of the longest.
+    pub fn longest_plateau(&self) -> Option<Run> {
+        longest(self.plateaus.iter().copied())
+    }
+}
+
+/// Every maximal run of two or more readings where each neighbouring pair
+/// satisfies `continues`.
+fn runs(depths: &[isize], continues: impl Fn(isize, isize) -> bool) -> Vec<Run> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for i in 1..=depths.len() {
+        if i == depths.len() || !continues(depths[i - 1], depths[i]) {
+            if i - start >= 2 {
+                runs.push(Run { start, len: i - start });
+            }
+            start = i;
+        }
+    }
+    runs
+}
+
+// Unlike `max_by_key`, ties go to the earliest run.
+fn longest(runs: impl IntoIterator<Item = Run>) -> Option<Run> {
+    runs.into_iter().reduce(|best, run| if run.len > best.len { run } else { best })
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let run = |run: Option<Run>| match run {
+            Some(run) => format!("{} readings from index {}", run.len, run.start),
+            None => "none".to_owned(),
+        };
+        let step = |step: Option<Step>| match step {
+            Some(step) => format!("{:+} at index {}", step.change, step.index),
+            None => "none".to_owned(),
+        };
+        let plateaus = match self.longest_plateau() {
+            Some(longest) => format!("{}, longest is {}", self.plateaus.len(), run(Some(longest))),
+            None => "none".to_owned(),
+        };
+
+        let rows = [
+            ("Readings", self.readings.to_string()),
+            ("Min depth", self.min.to_string()),
+            ("Max depth", self.max.to_string()),
+            ("Mean depth", format!("{:.2}", self.mean)),
+            ("Longest increase", run(self.longest_increase)),
+            ("Longest decrease", run(self.longest_decrease)),
+            ("Largest jump", step(self.largest_jump)),
+            ("Largest drop", step(self.largest_drop)),
+            ("Plateaus", plateaus),
+        ];
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (i, (name, value)) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:width$}  {}", name, value, width = width)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+use anyhow::{bail, Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+use std::{fmt, io::Write, str::FromStr};
+
+pub mod script;
+
+pub type Command = (Direction, isize);
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "forward" => Ok(Direction::Forward),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            _ => Err(ParseError::new(value, value, "`forward`, `up` or `down`")),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Forward => write!(f, "forward"),
+            Direction::Up => write!(f, "up"),
+            Direction::Down => write!(f, "down"),
+        }
+    }
+}
+
+/// The integers positions are tracked in. Every operation is checked, so
+/// a model reports an overflow instead of wrapping around.
+///
+/// `isize` is enough for the puzzle; `i128` is there for enormous command logs.
+pub trait Number: Copy + Default + Ord + fmt::Display + fmt::Debug + FromStr {
+    fn from_amount(amount: isize) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {$(
+        impl Number for $t {
+            fn from_amount(amount: isize) -> Self {
+                amount as $t
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        }
+    )*};
+}
+
+impl_number!(isize, i128);
+
+/// One way of interpreting commands.
+///
+/// The puzzle changes what `up` and `down` mean between parts, so each
+/// meaning is a model and the commands are parsed once for all of them.
+pub trait SubmarineModel {
+    type Number: Number;
+
+    /// Fails, leaving the model unchanged, if the command would overflow.
+    fn apply(&mut self, command: Command) -> Result<()>;
+
+    /// Where the submarine is, as `(horizontal, depth)`.
+    fn position(&self) -> (Self::Number, Self::Number);
+
+    /// The aim, for models that have one.
+    fn aim(&self) -> Option<Self::Number> {
+        None
+    }
+}
+
+fn checked<N: Number>(result: Option<N>, what: &str) -> Result<N> {
+    result.with_context(|| format!("the {} overflowed", what))
+}
+
+/// Part 1: `up` and `down` change the depth directly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Simple<N = isize> {
+    horizontal: N,
+    depth: N,
+}
+
+impl<N: Number> SubmarineModel for Simple<N> {
+    type Number = N;
+
+    fn apply(&mut self, (dir, amount): Command) -> Result<()> {
+        let amount = N::from_amount(amount);
+        match dir {
+            Direction::Forward => self.horizontal = checked(self.horizontal.checked_add(amount), "horizontal position")?,
+            Direction::Up => self.depth = checked(self.depth.checked_sub(amount), "depth")?,
+            Direction::Down => self.depth = checked(self.depth.checked_add(amount), "depth")?,
+        }
+        Ok(())
+    }
+
+    fn position(&self) -> (N, N) {
+        (self.horizontal, self.depth)
+    }
+}
+
+/// Part 2: `up` and `down` change the aim, and moving forward dives along it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aimed<N = isize> {
+    horizontal: N,
+    depth: N,
+    aim: N,
+}
+
+impl<N: Number> SubmarineModel for Aimed<N> {
+    type Number = N;
+
+    fn apply(&mut self, (dir, amount): Command) -> Result<()> {
+        let amount = N::from_amount(amount);
+        match dir {
+            Direction::Forward => {
+                let dive = checked(amount.checked_mul(self.aim), "depth")?;
+                let depth = checked(self.depth.checked_add(dive), "depth")?;
+                self.horizontal = checked(self.horizontal.checked_add(amount), "horizontal position")?;
+                self.depth = depth;
+            }
+            Direction::Up => self.aim = checked(self.aim.checked_sub(amount), "aim")?,
+            Direction::Down => self.aim = checked(self.aim.checked_add(amount), "aim")?,
+        }
+        Ok(())
+    }
+
+    fn position(&self) -> (N, N) {
+        (self.horizontal, self.depth)
+    }
+
+    fn aim(&self) -> Option<N> {
+        Some(self.aim)
+    }
+}
+
+/// The names `model` accepts, with what each one does.
+pub const MODELS: &[(&str, &str)] = &[
+    ("simple", "up and down change the depth (part 1)"),
+    ("aimed", "up and down change the aim (part 2)"),
+];
+
+/// Creates the model called `name`, tracking positions in `N`.
+pub fn model<N: Number + 'static>(name: &str) -> Result<Box<dyn SubmarineModel<Number = N>>> {
+    match name {
+        "simple" => Ok(Box::new(Simple::<N>::default())),
+        "aimed" => Ok(Box::new(Aimed::<N>::default())),
+        _ => {
+            let names = MODELS.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            bail!("unknown model {}, expected one of {}", name, names.join(", "))
+        }
+    }
+}
+
+/// Applies `command`, the `index`th, naming it if it overflows.
+fn apply<M: SubmarineModel + ?Sized>(model: &mut M, index: usize, command: Command) -> Result<()> {
+    model.apply(command).with_context(|| format!("command {} (`{} {}`)", index, command.0, command.1))
+}
+
+/// Applies every command in turn and returns where the submarine ends up.
+pub fn navigate<M: SubmarineModel + ?Sized>(model: &mut M, commands: &[Command]) -> Result<(M::Number, M::Number)> {
+    for (i, command) in commands.iter().enumerate() {
+        apply(model, i, *command)?;
+    }
+    Ok(model.position())
+}
+
+/// `horizontal * depth`, the puzzle's answer for a position.
+pub fn product<N: Number>((horizontal, depth): (N, N)) -> Result<N> {
+    checked(horizontal.checked_mul(depth), "product of the horizontal position and depth")
+}
+
+/// A model's state after a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State<N = isize> {
+    pub horizontal: N,
+    pub depth: N,
+    pub aim: Option<N>,
+}
+
+impl<N: Number> State<N> {
+    fn of<M: SubmarineModel<Number = N> + ?Sized>(model: &M) -> Self {
+        let (horizontal, depth) = model.position();
+        State { horizontal, depth, aim: model.aim() }
+    }
+}
+
+/// Every command along with the state it left the submarine in.
+#[derive(Debug, Clone)]
+pub struct Trajectory<N = isize> {
+    start: State<N>,
+    steps: Vec<(Command, State<N>)>,
+}
+
+impl<N: Number> Trajectory<N> {
+    /// Applies every command in turn, recording the state after each one.
+    pub fn record<M: SubmarineModel<Number = N> + ?Sized>(model: &mut M, commands: &[Command]) -> Result<Self> {
+        let start = State::of(model);
+        let steps = commands.iter().enumerate().map(|(i, command)| {
+            apply(model, i, *command)?;
+            Ok((*command, State::of(model)))
+        }).collect::<Result<_>>()?;
+
+        Ok(Trajectory { start, steps })
+    }
+
+    pub fn steps(&self) -> &[(Command, State<N>)] {
+        &self.steps
+    }
+
+    /// Where the submarine ends up, which is where it started if there were
+    /// no commands.
+    pub fn end(&self) -> State<N> {
+        self.steps.last().map_or(self.start, |(_, state)| *state)
+    }
+
+    /// The deepest point reached, as the index of the first command to
+    /// reach it and the depth.
+    pub fn max_depth(&self) -> Option<(usize, N)> {
+        self.depths().reduce(|deepest, step| if step.1 > deepest.1 { step } else { deepest })
+    }
+
+    /// The index of the first command that took the submarine from above
+    /// `depth` to at or below it.
+    pub fn first_crossing(&self, depth: N) -> Option<usize> {
+        let mut above = self.start.depth < depth;
+        self.depths().find_map(|(i, d)| {
+            let crossed = above && d >= depth;
+            above = d < depth;
+            crossed.then_some(i)
+        })
+    }
+
+    fn depths(&self) -> impl Iterator<Item = (usize, N)> + '_ {
+        self.steps.iter().enumerate().map(|(i, (_, state))| (i, state.depth))
+    }
+
+    /// Writes one CSV row per command. Models without an aim leave that
+    /// column empty.
+    pub fn write_csv(&self, mut out: impl Write) -> std::io::Result<()> {
+        writeln!(out, "index,direction,amount,horizontal,depth,aim")?;
+        for (i, ((dir, amount), state)) in self.steps.iter().enumerate() {
+            let aim = state.aim.map(|aim| aim.to_string()).unwrap_or_default();
+            writeln!(out, "{},{},{},{},{},{}", i, dir, amount, state.horizontal, state.depth, aim)?;
+        }
+        Ok(())
+    }
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Dive!";
+
+    type Input<'a> = Vec<Command>;
+    type Output1 = isize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        let commands = parse::lines(input, |l| {
+            let (dir, amount) = parse::split_once(l, " ")?;
+            let dir = dir.parse::<Direction>().map_err(|e| e.rebase(l, dir))?;
+            let amount = parse::number(l, amount)?;
+            Ok((dir, amount))
+        })?;
+        Ok(commands)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<isize> {
+        product(navigate(&mut Simple::default(), commands)?)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<isize> {
+        product(navigate(&mut Aimed::default(), commands)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        assert_eq!(Day02::part1(&input)?, 150);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        assert_eq!(Day02::part2(&input)?, 900);
+        Ok(())
+    }
+
+    #[test]
+    fn models_by_name() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        assert_eq!(navigate(model::<isize>("simple")?.as_mut(), &input)?, (15, 10));
+        assert_eq!(navigate(model::<i128>("aimed")?.as_mut(), &input)?, (15, 60));
+        assert!(model::<isize>("sideways").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn scripts() -> Result<()> {
+        let source = "\
+# The example, with a macro and a loop.
+macro dive {
+    down 5  # aim down
+    forward 8
+}
+
+forward 5
+repeat 1 {
+    dive
+}
+up 3
+repeat 2 {
+    down 4
+}
+forward 4
+back 2
+";
+        let commands = script::compile(source)?;
+        assert_eq!(navigate(&mut Aimed::<isize>::default(), &commands)?, navigate(&mut Aimed::default(), &Day02::parse(EXAMPLE)?)?);
+
+        let errors = ["repeat 2 {\n  forward 1\n", "forward 1\nsideways 2", "repeat x {\n}", "dive"];
+        let lines = errors.iter().map(|s| script::compile(s).unwrap_err().line).collect::<Vec<_>>();
+        assert_eq!(lines, vec![1, 2, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn overflow_names_the_command() -> Result<()> {
+        let commands = vec![(Direction::Down, isize::MAX), (Direction::Forward, 1), (Direction::Forward, 2)];
+        let error = navigate(&mut Aimed::<isize>::default(), &commands).unwrap_err();
+        assert_eq!(format!("{:#}", error), "command 2 (`forward 2`): the depth overflowed");
+
+        let wide = navigate(&mut Aimed::<i128>::default(), &commands)?;
+        assert_eq!(wide, (3, 3 * isize::MAX as i128));
+        assert!(product(navigate(&mut Simple::<isize>::default(), &commands)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn trajectory_queries() -> Result<()> {
+        let input = Day02::parse(EXAMPLE)?;
+        let trajectory = Trajectory::record(&mut Aimed::<isize>::default(), &input)?;
+
+        assert_eq!(trajectory.end(), State { horizontal: 15, depth: 60, aim: Some(10) });
+        assert_eq!(trajectory.max_depth(), Some((5, 60)));
+        assert_eq!(trajectory.first_crossing(40), Some(2));
+        assert_eq!(trajectory.first_crossing(61), None);
+
+        let mut csv = Vec::new();
+        trajectory.write_csv(&mut csv)?;
+        let csv = String::from_utf8(csv)?;
+        assert_eq!(csv.lines().nth(1), Some("0,forward,5,5,0,0"));
+        assert_eq!(csv.lines().count(), input.len() + 1);
+        Ok(())
+    }
+}
@@ -1,64 +1,104 @@
-use anyhow::{anyhow, Context, Result};
-use std::str::FromStr;
+use anyhow::{bail, Context, Result};
+use aoc::{Args, Puzzle};
+use day02::{script, Command, Day02, Number, Trajectory, INPUT_PATH, MODELS};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
 
-const INPUT: &'static str = include_str!("../input.txt");
+const USAGE: &str = "       day02 [--model <name>] [--csv <path|->] [--crossing <depth>] [--script] [--wide] [--input <path|->]";
 
-type Command = (Direction, isize);
+const MODES: &[&str] = &["--model", "-m", "--csv", "--crossing", "--script", "--wide"];
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Forward,
-    Up,
-    Down,
+fn main() {
+    let models = MODELS.iter().map(|(name, description)| format!("  {:8} {}", name, description));
+    let usage = format!("{}\n\nmodels:\n{}", USAGE, models.collect::<Vec<_>>().join("\n"));
+    aoc::main_with_modes::<Day02>(INPUT_PATH, MODES, &usage, try_main);
 }
 
-impl FromStr for Direction {
-    type Err = anyhow::Error;
+/// Options for exploring the commands with a single model.
+struct Explore {
+    model: String,
+    csv: Option<String>,
+    crossing: Option<String>,
+}
+
+/// Navigates with the model named by `--model`, `aimed` by default, and
+/// prints where it ends up and how deep it went. With `--script` the input is
+/// compiled as a `day02::script` rather than parsed as plain commands, and
+/// with `--wide` positions are tracked in `i128` rather than `isize`.
+fn try_main(args: &mut Args, path: &mut String) -> Result<()> {
+    let mut explore = Explore { model: "aimed".to_owned(), csv: None, crossing: None };
+    let mut is_script = false;
+    let mut wide = false;
 
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match value {
-            "forward" => Ok(Direction::Forward),
-            "up" => Ok(Direction::Up),
-            "down" => Ok(Direction::Down),
-            _ => Err(anyhow!("Invalid direction")),
+    while let Some(name) = args.next_flag()? {
+        match name.as_str() {
+            "--script" => is_script = true,
+            "--wide" => wide = true,
+            "--model" | "-m" => explore.model = args.value()?,
+            "--csv" => explore.csv = Some(args.value()?),
+            "--crossing" => explore.crossing = Some(args.value()?),
+            "--input" | "-i" => *path = args.value()?,
+            _ => bail!("unexpected argument {}", name),
         }
     }
-}
 
-fn main() -> Result<()> {
-    let commands = INPUT.lines()
-        .map(|l| {
-            let (dir, amount) = l.split_once(" ").context("unexpected format")?;
-            let dir = dir.parse()?;
-            let amount = amount.parse()?;
-            Ok((dir, amount))
-        })
-        .collect::<Result<Vec<Command>>>()?;
+    let input = aoc::read_input(path)?;
+    let commands = match is_script {
+        true => script::compile(&input)?,
+        false => Day02::parse(&input)?,
+    };
 
-    println!("part 1: {}", part1(&commands));
-    println!("part 2: {}", part2(&commands));
-    
-    Ok(())
+    match wide {
+        true => explore.run::<i128>(&commands),
+        false => explore.run::<isize>(&commands),
+    }
 }
 
-fn part1(input: &[Command]) -> isize {
-    let (horizontal, depth) = input.iter().fold((0, 0), |(h, d), (dir, amount)| {
-        match dir {
-            Direction::Forward => (h + amount, d),
-            Direction::Up => (h, d - amount),
-            Direction::Down => (h, d + amount),
+impl Explore {
+    fn run<N: Number + 'static>(&self, commands: &[Command]) -> Result<()> {
+        let crossing = match &self.crossing {
+            Some(depth) => Some(depth.parse::<N>().ok().with_context(|| format!("invalid depth {}", depth))?),
+            None => None,
+        };
+        let mut model = day02::model::<N>(&self.model)?;
+        let trajectory = Trajectory::record(model.as_mut(), commands)?;
+
+        // With the CSV on stdout, the summary would get in the way.
+        if let Some(csv) = &self.csv {
+            return write_csv(&trajectory, csv);
         }
-    });
-    horizontal * depth
-}
 
-fn part2(input: &[Command]) -> isize {
-    let (horizontal, depth, _) = input.iter().fold((0, 0, 0), |(h, d, aim), (dir, amount)| {
-        match dir {
-            Direction::Forward => (h + amount, d + (amount * aim), aim),
-            Direction::Up => (h, d, aim - amount),
-            Direction::Down => (h, d, aim + amount),
+        let end = trajectory.end();
+        let answer = day02::product((end.horizontal, end.depth))?;
+        println!("horizontal {}, depth {}: {}", end.horizontal, end.depth, answer);
+        if let Some((i, depth)) = trajectory.max_depth() {
+            println!("deepest point {} after command {} ({})", depth, i, describe(&trajectory, i));
         }
-    });
-    horizontal * depth
+        if let Some(depth) = crossing {
+            match trajectory.first_crossing(depth) {
+                Some(i) => println!("first crossed depth {} at command {} ({})", depth, i, describe(&trajectory, i)),
+                None => println!("never crossed depth {}", depth),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn describe<N: Number>(trajectory: &Trajectory<N>, i: usize) -> String {
+    let ((dir, amount), _) = trajectory.steps()[i];
+    format!("{} {}", dir, amount)
+}
+
+fn write_csv<N: Number>(trajectory: &Trajectory<N>, path: &str) -> Result<()> {
+    let out: Box<dyn Write> = if path == aoc::STDIN {
+        Box::new(io::stdout().lock())
+    } else {
+        Box::new(File::create(path).with_context(|| format!("failed to create {}", path))?)
+    };
+    let mut out = BufWriter::new(out);
+    trajectory.write_csv(&mut out)?;
+    out.flush()?;
+    Ok(())
 }
@@ -0,0 +1,121 @@
+//! Submarine scripts: the puzzle's commands plus comments, a `back`
+//! direction, `repeat` blocks and macros.
+//!
+//! ```text
+//! # Dive along a staircase.
+//! macro step {
+//!     down 2
+//!     forward 5
+//! }
+//!
+//! repeat 3 {
+//!     step
+//! }
+//! back 4
+//! ```
+//!
+//! Scripts compile to the same commands the puzzle input parses to, so every
+//! `SubmarineModel` can run them. `back N` is `forward -N`.
+
+use crate::{Command, Direction};
+use aoc::{parse, ParseError};
+use std::{collections::HashMap, str::Lines};
+
+/// Scripts that would expand to more commands than this are rejected, so a
+/// few nested `repeat`s can't exhaust memory.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+/// Compiles `source` to the commands it runs.
+pub fn compile(source: &str) -> Result<Vec<Command>, ParseError> {
+    let mut compiler = Compiler { source, lines: source.lines(), macros: HashMap::new() };
+    compiler.block(None)
+}
+
+struct Compiler<'a> {
+    source: &'a str,
+    lines: Lines<'a>,
+    /// Macros compiled so far. A macro can only use macros defined before it,
+    /// so none can be recursive.
+    macros: HashMap<&'a str, Vec<Command>>,
+}
+
+impl<'a> Compiler<'a> {
+    /// Compiles lines up to the `}` closing `opener`, or to the end of the
+    /// script at the top level.
+    fn block(&mut self, opener: Option<&'a str>) -> Result<Vec<Command>, ParseError> {
+        let mut commands = Vec::new();
+
+        while let Some(line) = self.lines.next() {
+            let code = line.split('#').next().unwrap_or("");
+            let words = code.split_whitespace().collect::<Vec<_>>();
+
+            match words[..] {
+                [] => {}
+                ["}"] if opener.is_some() => return Ok(commands),
+                ["}"] => return Err(self.at(line, words[0], "a command, there is no block to close")),
+                ["repeat", count, "{"] => {
+                    let count: usize = parse::number(line, count).map_err(|e| self.error(line, e))?;
+                    let body = self.block(Some(line))?;
+                    let total = body.len().checked_mul(count).and_then(|n| n.checked_add(commands.len()));
+                    if total.is_none_or(|total| total > MAX_COMMANDS) {
+                        return Err(self.at(line, words[1], format!("at most {} commands in total", MAX_COMMANDS)));
+                    }
+                    for _ in 0..count {
+                        commands.extend_from_slice(&body);
+                    }
+                }
+                ["macro", name, "{"] => {
+                    if opener.is_some() {
+                        return Err(self.at(line, words[0], "a command, macros can only be defined at the top level"));
+                    }
+                    if !is_name(name) || self.macros.contains_key(name) {
+                        return Err(self.at(line, name, "a new macro name"));
+                    }
+                    let body = self.block(Some(line))?;
+                    self.macros.insert(name, body);
+                }
+                [word, amount] => {
+                    let (direction, sign) = match word {
+                        "forward" => (Direction::Forward, 1),
+                        "back" => (Direction::Forward, -1),
+                        "up" => (Direction::Up, 1),
+                        "down" => (Direction::Down, 1),
+                        _ => return Err(self.at(line, word, "`forward`, `back`, `up` or `down`")),
+                    };
+                    let number: isize = parse::number(line, amount).map_err(|e| self.error(line, e))?;
+                    let number = number.checked_mul(sign).ok_or_else(|| self.at(line, amount, "a number that can be negated"))?;
+                    commands.push((direction, number));
+                }
+                [name] => match self.macros.get(name) {
+                    Some(body) => commands.extend_from_slice(body),
+                    None => return Err(self.at(line, name, "a command or a defined macro")),
+                },
+                _ => return Err(self.at(line, code.trim(), "a command, `repeat N {`, `macro NAME {` or `}`")),
+            }
+
+            if commands.len() > MAX_COMMANDS {
+                return Err(self.at(line, code.trim(), format!("at most {} commands in total", MAX_COMMANDS)));
+            }
+        }
+
+        match opener {
+            Some(opener) => Err(self.at(opener, opener.trim(), "a matching `}`")),
+            None => Ok(commands),
+        }
+    }
+
+    /// An error at `at`, which is part of `line`.
+    fn at(&self, line: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        self.error(line, ParseError::new(line, at, expected))
+    }
+
+    /// Moves an error in `line` to its place in the whole script.
+    fn error(&self, line: &str, error: ParseError) -> ParseError {
+        error.rebase(self.source, line)
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    const KEYWORDS: &[&str] = &["forward", "back", "up", "down", "repeat", "macro"];
+    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') && !KEYWORDS.contains(&name)
+}
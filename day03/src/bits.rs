@@ -0,0 +1,76 @@
+use aoc::ParseError;
+use num_bigint::BigUint;
+use std::{fmt, str::FromStr};
+
+/// A binary number of any width, such as one line of the diagnostic report.
+///
+/// Bits are numbered from the right, so bit 0 is the last character of the
+/// line, just like bit 0 of an integer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bits {
+    width: usize,
+    /// 64 bits per word, least significant word first.
+    words: Vec<u64>,
+}
+
+impl Bits {
+    pub fn zeros(width: usize) -> Self {
+        Bits { width, words: vec![0; width.div_ceil(64)] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.width, "bit {} of a {} bit number", i, self.width);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.width, "bit {} of a {} bit number", i, self.width);
+        if value {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    /// The bits 64 at a time, least significant word first.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::from_bytes_le(&self.words.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<_>>())
+    }
+}
+
+impl FromStr for Bits {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = Bits::zeros(s.len());
+        for (i, c) in s.char_indices() {
+            match c {
+                '0' => {}
+                '1' => bits.set(s.len() - 1 - i, true),
+                _ => return Err(ParseError::new(s, &s[i..i + c.len_utf8()], "`0` or `1`")),
+            }
+        }
+        if s.is_empty() {
+            return Err(ParseError::new(s, s, "a binary number"));
+        }
+        Ok(bits)
+    }
+}
+
+/// Writes the bits most significant first, the way they appear in the report.
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in (0..self.width).rev() {
+            write!(f, "{}", if self.get(i) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
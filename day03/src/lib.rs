@@ -0,0 +1,416 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+use num_bigint::BigUint;
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+mod bits;
+
+pub use bits::Bits;
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+
+    /// The diagnostic numbers along with their width in bits.
+    type Input<'a> = (Vec<Bits>, usize);
+    type Output1 = BigUint;
+    type Output2 = BigUint;
+
+    fn parse(input: &str) -> Result<(Vec<Bits>, usize)> {
+        let width = input.lines().next().context("expected data")?.len();
+        let numbers = parse::lines(input, |l| {
+            let number: Bits = l.parse()?;
+            if number.width() != width {
+                return Err(ParseError::new(l, l, format!("a binary number of {} bits", width)));
+            }
+            Ok(number)
+        })?;
+        Ok((numbers, width))
+    }
+
+    fn part1((numbers, width): &(Vec<Bits>, usize)) -> Result<BigUint> {
+        let (gamma, epsilon) = gamma_epsilon(numbers, *width, TieBreak::PreferOne)?;
+        Ok(gamma.to_biguint() * epsilon.to_biguint())
+    }
+
+    fn part2((numbers, width): &(Vec<Bits>, usize)) -> Result<BigUint> {
+        let (oxygen, co2) = life_support(numbers, *width, TieBreak::PreferOne)?;
+        Ok(oxygen.to_biguint() * co2.to_biguint())
+    }
+}
+
+/// Every rating in the diagnostic report, not just the puzzle's products.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    pub width: usize,
+    pub readings: usize,
+    /// How many numbers have each bit set, indexed by bit.
+    pub column_counts: Vec<usize>,
+    pub gamma: Bits,
+    pub epsilon: Bits,
+    pub oxygen: Bits,
+    pub co2: Bits,
+}
+
+impl DiagnosticReport {
+    pub fn new(numbers: &[Bits], width: usize, tie: TieBreak) -> Result<Self> {
+        if numbers.is_empty() {
+            bail!("there are no numbers to diagnose");
+        }
+        let (gamma, epsilon) = gamma_epsilon(numbers, width, tie)?;
+        let (oxygen, co2) = life_support(numbers, width, tie)?;
+
+        Ok(DiagnosticReport {
+            width,
+            readings: numbers.len(),
+            column_counts: column_counts(numbers, width),
+            gamma,
+            epsilon,
+            oxygen,
+            co2,
+        })
+    }
+
+    /// Gamma times epsilon, the answer to part 1.
+    pub fn power_consumption(&self) -> BigUint {
+        self.gamma.to_biguint() * self.epsilon.to_biguint()
+    }
+
+    /// Oxygen times CO2, the answer to part 2.
+    pub fn life_support_rating(&self) -> BigUint {
+        self.oxygen.to_biguint() * self.co2.to_biguint()
+    }
+}
+
+impl fmt::Display for DiagnosticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = self.column_counts.iter().rev().map(|c| c.to_string()).collect::<Vec<_>>();
+        writeln!(f, "Readings     {} of {} bits", self.readings, self.width)?;
+        writeln!(f, "Ones per bit {}", counts.join(" "))?;
+        for (name, bits) in [("Gamma", &self.gamma), ("Epsilon", &self.epsilon), ("Oxygen", &self.oxygen), ("CO2", &self.co2)] {
+            writeln!(f, "{:12} {} ({})", name, bits, bits.to_biguint())?;
+        }
+        writeln!(f, "Power        {}", self.power_consumption())?;
+        write!(f, "Life support {}", self.life_support_rating())
+    }
+}
+
+/// How many numbers have each bit set, indexed by bit.
+///
+/// The numbers are taken 64 rows at a time. Each block of 64 rows is
+/// transposed one word of 64 columns at a time, which leaves every column of
+/// the block in a single word, so counting its 1s is one `count_ones`.
+pub fn column_counts(numbers: &[Bits], width: usize) -> Vec<usize> {
+    let mut buckets = vec![0; width];
+    for block in numbers.chunks(64) {
+        for (w, columns) in buckets.chunks_mut(64).enumerate() {
+            let mut rows = [0; 64];
+            for (row, number) in rows.iter_mut().zip(block) {
+                *row = number.words()[w];
+            }
+            transpose(&mut rows);
+            // The transpose flips the order of the columns.
+            for (i, bucket) in columns.iter_mut().enumerate() {
+                *bucket += rows[63 - i].count_ones() as usize;
+            }
+        }
+    }
+    buckets
+}
+
+/// Transposes a 64×64 bit matrix in place by swapping ever smaller blocks
+/// across the diagonal, from 32×32 down to single bits.
+fn transpose(rows: &mut [u64; 64]) {
+    let mut size = 32;
+    let mut mask = 0x0000_0000_ffff_ffff_u64;
+    while size != 0 {
+        let mut k = 0;
+        while k < 64 {
+            let t = (rows[k] ^ (rows[k + size] >> size)) & mask;
+            rows[k] ^= t;
+            rows[k + size] ^= t << size;
+            k = (k + size + 1) & !size;
+        }
+        size >>= 1;
+        mask ^= mask << size;
+    }
+}
+
+/// How many numbers have each bit set, counted one bit of one number at a
+/// time.
+///
+/// It's simple enough to trust by reading, so the tests check the transposed
+/// counts of `column_counts` against it. The `day03_column_counts` benchmark
+/// compares the two.
+pub fn column_counts_by_folding(numbers: &[Bits], width: usize) -> Vec<usize> {
+    numbers.iter().fold(vec![0; width], |mut buckets, number| {
+        for (i, bucket) in buckets.iter_mut().enumerate() {
+            if number.get(i) {
+                *bucket += 1;
+            }
+        }
+        buckets
+    })
+}
+
+/// What to do when a bit position has as many 0s as 1s.
+///
+/// The policy picks the most common bit on a tie, and the least common bit
+/// is always the other one. The puzzle wants `PreferOne`: gamma and oxygen
+/// take a tied bit as 1, epsilon and CO2 take it as 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TieBreak {
+    #[default]
+    PreferOne,
+    PreferZero,
+    Error,
+}
+
+impl TieBreak {
+    /// The most common bit given how many 0s and 1s there are, or `None` for
+    /// a tie under `TieBreak::Error`.
+    pub fn most_common(self, zeros: usize, ones: usize) -> Option<bool> {
+        match ones.cmp(&zeros) {
+            Ordering::Greater => Some(true),
+            Ordering::Less => Some(false),
+            Ordering::Equal => match self {
+                TieBreak::PreferOne => Some(true),
+                TieBreak::PreferZero => Some(false),
+                TieBreak::Error => None,
+            },
+        }
+    }
+
+    /// The least common bit, the opposite of `most_common`.
+    pub fn least_common(self, zeros: usize, ones: usize) -> Option<bool> {
+        self.most_common(zeros, ones).map(|bit| !bit)
+    }
+}
+
+impl FromStr for TieBreak {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" | "one" => Ok(TieBreak::PreferOne),
+            "0" | "zero" => Ok(TieBreak::PreferZero),
+            "error" => Ok(TieBreak::Error),
+            _ => bail!("invalid tie break {}, expected 1, 0 or error", s),
+        }
+    }
+}
+
+fn tied(rating: &str, bit: usize, count: usize) -> anyhow::Error {
+    anyhow!("the {} rating is tied on bit {} with {} of each", rating, bit, count)
+}
+
+/// The gamma and epsilon rates, made of the most and least common bits.
+fn gamma_epsilon(numbers: &[Bits], width: usize, tie: TieBreak) -> Result<(Bits, Bits)> {
+    let mut gamma = Bits::zeros(width);
+    let mut epsilon = Bits::zeros(width);
+    for (i, ones) in column_counts(numbers, width).into_iter().enumerate() {
+        let zeros = numbers.len() - ones;
+        let bit = tie.most_common(zeros, ones).ok_or_else(|| tied("gamma", i, ones))?;
+        gamma.set(i, bit);
+        epsilon.set(i, !bit);
+    }
+    Ok((gamma, epsilon))
+}
+
+/// How many of `numbers` have a 0 and a 1 at `pos`.
+fn count_bit(numbers: &[Bits], pos: usize) -> (usize, usize) {
+    let ones = numbers.iter().filter(|number| number.get(pos)).count();
+    (numbers.len() - ones, ones)
+}
+
+/// The oxygen generator and CO2 scrubber ratings, found by filtering copies
+/// of `numbers` one bit at a time and recounting each time.
+///
+/// Each step copies and recounts whatever is left, which is what made part 2
+/// slow on big reports. It stays as the plain reading of the puzzle that
+/// `life_support` is tested against, and as the baseline in the
+/// `day03_life_support` benchmark. Unlike `life_support` it gives no reason
+/// when it finds nothing.
+pub fn life_support_by_filtering(numbers: &[Bits], width: usize, tie: TieBreak) -> Option<(Bits, Bits)> {
+    let mut oxygen_open_list = numbers.to_vec();
+    // Whyyy is the first bit really the highest bit??
+    for i in (0..width).rev() {
+        if oxygen_open_list.len() <= 1 {
+            break;
+        }
+        let (zeros, ones) = count_bit(&oxygen_open_list, i);
+        let keep = tie.most_common(zeros, ones)?;
+        oxygen_open_list.retain(|number| number.get(i) == keep);
+    }
+
+    let mut co2_open_list = numbers.to_vec();
+    for i in (0..width).rev() {
+        if co2_open_list.len() <= 1 {
+            break;
+        }
+        let (zeros, ones) = count_bit(&co2_open_list, i);
+        let keep = tie.least_common(zeros, ones)?;
+        co2_open_list.retain(|number| number.get(i) == keep);
+    }
+
+    Some((oxygen_open_list.first()?.clone(), co2_open_list.first()?.clone()))
+}
+
+/// The oxygen generator and CO2 scrubber ratings. It's an error for a
+/// filter to leave no numbers, which happens when there are none to begin
+/// with or a filter has to keep a bit that none of them have.
+///
+/// Rather than copying the numbers that pass each filter step, the numbers
+/// still in the running are kept together at the front of one list of
+/// references. Each step partitions them in place by the next bit, 0s first,
+/// and narrows down to one side. That's a single pass over the remaining
+/// numbers per bit, so O(n·w) at worst and usually closer to O(n).
+pub fn life_support(numbers: &[Bits], width: usize, tie: TieBreak) -> Result<(Bits, Bits)> {
+    let mut candidates = numbers.iter().collect::<Vec<_>>();
+
+    let oxygen = narrow(&mut candidates, width, "oxygen generator", |zeros, ones| tie.most_common(zeros, ones))?;
+    // Narrowing only reorders the candidates, so they can be used again.
+    let co2 = narrow(&mut candidates, width, "CO2 scrubber", |zeros, ones| tie.least_common(zeros, ones))?;
+    Ok((oxygen.clone(), co2.clone()))
+}
+
+/// Narrows `candidates` down one bit at a time from the top, keeping the bit
+/// `keep(zeros, ones)` picks. It's an error for it to pick neither.
+fn narrow<'a>(
+    candidates: &mut [&'a Bits],
+    width: usize,
+    rating: &str,
+    keep: impl Fn(usize, usize) -> Option<bool>,
+) -> Result<&'a Bits> {
+    let mut range = candidates;
+    for i in (0..width).rev() {
+        if range.len() <= 1 {
+            break;
+        }
+        let split = partition(range, |number| !number.get(i));
+        let (zeros, ones) = range.split_at_mut(split);
+        let keep_ones = keep(zeros.len(), ones.len()).ok_or_else(|| tied(rating, i, zeros.len()))?;
+        range = if keep_ones { ones } else { zeros };
+        if range.is_empty() {
+            bail!("no numbers are left for the {} rating after filtering on bit {}", rating, i);
+        }
+    }
+    range.first().copied().with_context(|| format!("there are no numbers to find the {} rating in", rating))
+}
+
+/// Moves the items matching `first` to the front, returning how many there are.
+fn partition<T>(items: &mut [T], first: impl Fn(&T) -> bool) -> usize {
+    let mut split = 0;
+    for i in 0..items.len() {
+        if first(&items[i]) {
+            items.swap(split, i);
+            split += 1;
+        }
+    }
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day03::parse(EXAMPLE)?;
+        assert_eq!(Day03::part1(&input)?, 198u32.into());
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day03::parse(EXAMPLE)?;
+        assert_eq!(Day03::part2(&input)?, 230u32.into());
+        Ok(())
+    }
+
+    #[test]
+    fn example_report() -> Result<()> {
+        let (numbers, width) = Day03::parse(EXAMPLE)?;
+        let report = DiagnosticReport::new(&numbers, width, TieBreak::PreferOne)?;
+        assert_eq!(report.column_counts, vec![5, 7, 8, 5, 7]);
+        let ratings = [&report.gamma, &report.epsilon, &report.oxygen, &report.co2].map(|r| r.to_string());
+        assert_eq!(ratings, ["10110", "01001", "10111", "01010"]);
+        assert_eq!(report.power_consumption(), 198u32.into());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_filters_are_errors() -> Result<()> {
+        // Every number has a 1 in the top bit, so there's no 0 to keep for CO2.
+        let (numbers, width) = Day03::parse("110\n101\n")?;
+        let error = DiagnosticReport::new(&numbers, width, TieBreak::PreferOne).unwrap_err();
+        assert_eq!(error.to_string(), "no numbers are left for the CO2 scrubber rating after filtering on bit 2");
+        assert!(DiagnosticReport::new(&[], 3, TieBreak::PreferOne).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn exact_ties() -> Result<()> {
+        // Both numbers disagree on every bit, so every rating starts with a tie.
+        let (numbers, width) = Day03::parse("10\n01\n")?;
+        let ratings = |tie| -> Result<[String; 4]> {
+            let report = DiagnosticReport::new(&numbers, width, tie)?;
+            Ok([&report.gamma, &report.epsilon, &report.oxygen, &report.co2].map(|r| r.to_string()))
+        };
+        assert_eq!(ratings(TieBreak::PreferOne)?, ["11", "00", "10", "01"]);
+        assert_eq!(ratings(TieBreak::PreferZero)?, ["00", "11", "01", "10"]);
+        let error = ratings(TieBreak::Error).unwrap_err();
+        assert_eq!(error.to_string(), "the gamma rating is tied on bit 0 with 1 of each");
+
+        // Three numbers can't tie on a column, but they can once filtering leaves two.
+        let (numbers, width) = Day03::parse("11\n10\n01\n")?;
+        let (gamma, epsilon) = gamma_epsilon(&numbers, width, TieBreak::Error)?;
+        assert_eq!([gamma.to_string(), epsilon.to_string()], ["11", "00"]);
+        let error = life_support(&numbers, width, TieBreak::Error).unwrap_err();
+        assert_eq!(error.to_string(), "the oxygen generator rating is tied on bit 0 with 1 of each");
+        Ok(())
+    }
+
+    #[test]
+    fn column_counts_match_folding() -> Result<()> {
+        let real = include_str!("../input.txt");
+        // 130 bits spans three words, and 1000 rows leaves a partial block.
+        let wide = real.lines().map(|l| l.repeat(11)[..130].to_owned() + "\n").collect::<String>();
+        for input in [EXAMPLE, real, &wide] {
+            let (numbers, width) = Day03::parse(input)?;
+            assert_eq!(column_counts(&numbers, width), column_counts_by_folding(&numbers, width));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn life_support_matches_filtering() -> Result<()> {
+        let (numbers, width) = Day03::parse(include_str!("../input.txt"))?;
+        for tie in [TieBreak::PreferOne, TieBreak::PreferZero, TieBreak::Error] {
+            assert_eq!(life_support(&numbers, width, tie).ok(), life_support_by_filtering(&numbers, width, tie));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn wider_than_a_word() -> Result<()> {
+        // The example with every number repeated 15 times, so 75 bits wide.
+        let wide = EXAMPLE.lines().map(|l| l.repeat(15) + "\n").collect::<String>();
+        let input = Day03::parse(&wide)?;
+        assert_eq!(input.1, 75);
+        let rating = |bits: &str| BigUint::parse_bytes(bits.repeat(15).as_bytes(), 2).unwrap();
+        assert_eq!(Day03::part2(&input)?, rating("10111") * rating("01010"));
+
+        let number: Bits = format!("1{}1", "0".repeat(98)).parse()?;
+        assert_eq!(number.to_biguint(), (BigUint::from(1u32) << 99u32) + 1u32);
+        assert_eq!(number.to_string().len(), 100);
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Board, WinPattern};
+use std::collections::HashMap;
+
+/// Every board in a game, indexed so that a draw only touches the cells with
+/// the drawn number.
+///
+/// Each line of every pattern (each row and column for the puzzle's rules)
+/// keeps a count of its marked cells, so a board has won as soon as one of
+/// those counts reaches the length of its line. Marking a cell is O(1) for
+/// each line it is on, and a draw costs O(occurrences) of the number rather
+/// than a scan of every board.
+pub struct Game {
+    /// The cells each number appears in, as `(board, cell)` in board order.
+    index: HashMap<usize, Vec<(usize, usize)>>,
+    /// The lines of each size of board in the game.
+    shapes: Vec<Shape>,
+    boards: Vec<BoardState>,
+    marked: Vec<bool>,
+    /// How many cells of each line of each board are marked.
+    hits: Vec<usize>,
+}
+
+/// The lines of every board of one size, which are the same for all of them.
+struct Shape {
+    /// The lines cell `i` is on are `cell_lines[line_starts[i]..line_starts[i + 1]]`.
+    line_starts: Vec<usize>,
+    cell_lines: Vec<usize>,
+    lengths: Vec<usize>,
+}
+
+struct BoardState {
+    shape: usize,
+    /// Where the board's cells start in `Game::marked` and its lines in `Game::hits`.
+    first_cell: usize,
+    first_line: usize,
+    /// The sum of the board's unmarked numbers.
+    unmarked: usize,
+    won: bool,
+}
+
+impl Shape {
+    fn new(width: usize, height: usize, patterns: &[WinPattern]) -> Self {
+        let lines = patterns.iter().flat_map(|pattern| pattern.lines(width, height)).collect::<Vec<_>>();
+        let mut on_line = vec![Vec::new(); width * height];
+        for (i, line) in lines.iter().enumerate() {
+            for &(x, y) in line {
+                on_line[y * width + x].push(i);
+            }
+        }
+
+        let mut line_starts = vec![0];
+        line_starts.extend(on_line.iter().scan(0, |start, lines| {
+            *start += lines.len();
+            Some(*start)
+        }));
+        Shape {
+            line_starts,
+            cell_lines: on_line.concat(),
+            lengths: lines.iter().map(|line| line.len()).collect(),
+        }
+    }
+}
+
+impl Game {
+    pub fn new(boards: &[Board], patterns: &[WinPattern]) -> Self {
+        let mut index = HashMap::<usize, Vec<(usize, usize)>>::new();
+        let mut shapes = Vec::new();
+        let mut shape_ids = HashMap::new();
+        let mut states = Vec::with_capacity(boards.len());
+        let (mut cells, mut lines) = (0, 0);
+
+        for (b, board) in boards.iter().enumerate() {
+            let (width, height) = (board.width(), board.height());
+            // Boards of the same size have the same lines, so they're only worked out once.
+            let shape = *shape_ids.entry((width, height)).or_insert_with(|| {
+                shapes.push(Shape::new(width, height, patterns));
+                shapes.len() - 1
+            });
+
+            for (i, entry) in board.entries.values().enumerate() {
+                index.entry(entry.value).or_default().push((b, i));
+            }
+            states.push(BoardState {
+                shape,
+                first_cell: cells,
+                first_line: lines,
+                unmarked: board.entries.values().map(|e| e.value).sum(),
+                won: false,
+            });
+            cells += width * height;
+            lines += shapes[shape].lengths.len();
+        }
+
+        Game { index, shapes, boards: states, marked: vec![false; cells], hits: vec![0; lines] }
+    }
+
+    /// Marks `number` on every board that hasn't won yet and returns the
+    /// boards that win because of it, in order.
+    pub fn draw(&mut self, number: usize) -> Vec<usize> {
+        let mut winners = Vec::new();
+        let Some(cells) = self.index.get(&number) else {
+            return winners;
+        };
+
+        for &(b, cell) in cells {
+            let board = &mut self.boards[b];
+            let marked = &mut self.marked[board.first_cell + cell];
+            if board.won || *marked {
+                continue;
+            }
+            *marked = true;
+            board.unmarked -= number;
+
+            let shape = &self.shapes[board.shape];
+            for &line in &shape.cell_lines[shape.line_starts[cell]..shape.line_starts[cell + 1]] {
+                let hits = &mut self.hits[board.first_line + line];
+                *hits += 1;
+                // A board's cells are all together, so it can only be the last winner so far.
+                if *hits == shape.lengths[line] && winners.last() != Some(&b) {
+                    winners.push(b);
+                }
+            }
+        }
+
+        // Boards stop being marked once they win, but not until every
+        // copy of the number on them is marked.
+        for &b in &winners {
+            self.boards[b].won = true;
+        }
+        winners
+    }
+
+    /// The sum of `board`'s unmarked numbers times `number`.
+    pub fn score(&self, board: usize, number: usize) -> usize {
+        self.boards[board].unmarked * number
+    }
+}
@@ -0,0 +1,300 @@
+use std::str::FromStr;
+use anyhow::{anyhow, Result};
+use aoc::{grid::Grid, parse, ParseError, Puzzle};
+
+mod game;
+mod pattern;
+
+pub use game::Game;
+pub use pattern::WinPattern;
+
+#[derive(Clone, Debug)]
+struct Entry {
+    value: usize,
+    marked: bool,
+}
+
+/// A bingo board of any size. The size comes from the block it is parsed
+/// from: one row per line and one column per number on a line.
+#[derive(Clone, Debug)]
+pub struct Board {
+    entries: Grid<Entry>,
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+        let rows = parse::lines(s, |line| {
+            let row = line.split_whitespace()
+                .map(|num| parse::number(line, num).map(|value| Entry { value, marked: false }))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                // Blank lines, such as extras between boards, aren't rows.
+                _ if row.is_empty() => Ok(row),
+                Some(width) if width != row.len() => Err(ParseError::new(line, line, format!("a row of {} numbers", width))),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        let width = width.ok_or_else(|| ParseError::new(s, s, "a board of numbers"))?;
+        Ok(Board { entries: Grid::from_cells(width, rows.into_iter().flatten().collect()) })
+    }
+}
+
+impl Board {
+    pub fn width(&self) -> usize {
+        self.entries.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.entries.height()
+    }
+
+    fn mark(&mut self, number: usize) {
+        for entry in self.entries.values_mut() {
+            if entry.value == number {
+                entry.marked = true;
+                // TODO: I think we can return early if
+                // a number cannot be seen twice on the
+                // same board.
+            }
+        }
+    }
+
+    fn is_winner(&self, patterns: &[WinPattern]) -> bool {
+        patterns.iter()
+            .flat_map(|pattern| pattern.lines(self.width(), self.height()))
+            .any(|line| line.iter().all(|&point| self.entries[point].marked))
+    }
+
+    fn score(&self, number: usize) -> usize {
+        self.entries.values().filter(|e| !e.marked).map(|e| e.value).sum::<usize>() * number
+    }
+}
+
+/// The score of the first board to win with any of `patterns`.
+pub fn first_winner(numbers: &[usize], boards: &[Board], patterns: &[WinPattern]) -> Result<usize> {
+    let mut game = Game::new(boards, patterns);
+    for &number in numbers {
+        if let Some(&board) = game.draw(number).first() {
+            return Ok(game.score(board, number));
+        }
+    }
+
+    Err(anyhow!("Did not find a winner"))
+}
+
+/// The score of the last board to win with any of `patterns`.
+pub fn last_winner(numbers: &[usize], boards: &[Board], patterns: &[WinPattern]) -> Result<usize> {
+    let mut game = Game::new(boards, patterns);
+    let mut remaining = boards.len();
+    let mut last = None;
+    for &number in numbers {
+        if remaining == 0 {
+            break;
+        }
+        let winners = game.draw(number);
+        remaining -= winners.len();
+        if let Some(&board) = winners.last() {
+            last = Some(game.score(board, number));
+        }
+    }
+
+    last.ok_or_else(|| anyhow!("Did not find a winner"))
+}
+
+/// `first_winner` by marking every board and checking every line after
+/// each draw.
+///
+/// Each draw touches every cell of every board, but it can stop at the first
+/// board to win, so it needs far fewer draws than `last_winner_by_scanning`.
+/// The `day04_winners` benchmark compares it with `first_winner`, whose
+/// lead shrinks on many boards as building the index starts to dominate.
+pub fn first_winner_by_scanning(numbers: &[usize], mut boards: Vec<Board>, patterns: &[WinPattern]) -> Result<usize> {
+    for number in numbers {
+        for board in &mut boards {
+            board.mark(*number);
+            if board.is_winner(patterns) {
+                return Ok(board.score(*number));
+            }
+        }
+    }
+
+    Err(anyhow!("Did not find a winner"))
+}
+
+/// `last_winner` by marking every board and checking every line after each
+/// draw, then setting aside the boards that won.
+///
+/// Finding the last winner means playing nearly every draw, and
+/// re-partitioning the boards after each one, so this is where the index in
+/// `Game` pays off. It scores the last winner with the last number drawn, so
+/// the two only agree when every board wins.
+pub fn last_winner_by_scanning(numbers: &[usize], mut boards: Vec<Board>, patterns: &[WinPattern]) -> Result<usize> {
+    let mut last_number = 0;
+    let mut winners = vec![];
+    for number in numbers {
+        if boards.is_empty() {
+            break
+        }
+
+        last_number = *number;
+
+        for board in &mut boards {
+            board.mark(*number);
+        }
+        
+        let (new_winners, remaining): (Vec<Board>, Vec<Board>) = 
+            boards.into_iter().partition(|b| b.is_winner(patterns));
+
+        boards = remaining;
+        winners.extend(new_winners);
+    }
+
+    let last = winners.last().ok_or_else(|| anyhow!("Did not find a winner"))?;
+    Ok(last.score(last_number))
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Giant Squid";
+
+    /// The drawn numbers and the boards they are marked on.
+    type Input<'a> = (Vec<usize>, Vec<Board>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<usize>, Vec<Board>)> {
+        let mut parts = input.split("\n\n");
+        // `split` always yields at least one part, even for an empty input.
+        let numbers = parts.next().unwrap_or(input);
+        let numbers = parse::comma_separated(numbers).map_err(|e| e.rebase(input, numbers))?;
+        let boards = parts
+            .map(|b| b.parse::<Board>().map_err(|e| e.rebase(input, b)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((numbers, boards))
+    }
+
+    fn part1((numbers, boards): &(Vec<usize>, Vec<Board>)) -> Result<usize> {
+        first_winner(numbers, boards, WinPattern::STANDARD)
+    }
+
+    fn part2((numbers, boards): &(Vec<usize>, Vec<Board>)) -> Result<usize> {
+        last_winner(numbers, boards, WinPattern::STANDARD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day04::parse(EXAMPLE)?;
+        assert_eq!(Day04::part1(&input)?, 4512);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day04::parse(EXAMPLE)?;
+        assert_eq!(Day04::part2(&input)?, 1924);
+        Ok(())
+    }
+
+    #[test]
+    fn boards_of_any_size() -> Result<()> {
+        let small = Day04::parse("2,5,8\n\n1 2 3\n4 5 6\n7 8 9\n")?;
+        assert_eq!([small.1[0].width(), small.1[0].height()], [3, 3]);
+        // The middle column wins, leaving 1 + 3 + 4 + 6 + 7 + 9 unmarked.
+        assert_eq!(Day04::part1(&small)?, 30 * 8);
+
+        let rows = (0..10).map(|y| (0..10).map(|x| (y * 10 + x).to_string()).collect::<Vec<_>>().join(" "));
+        let draws = (90..100).map(|n: usize| n.to_string()).collect::<Vec<_>>().join(",");
+        let large = Day04::parse(&format!("{}\n\n{}\n", draws, rows.collect::<Vec<_>>().join("\n")))?;
+        assert_eq!([large.1[0].width(), large.1[0].height()], [10, 10]);
+        assert_eq!(Day04::part1(&large)?, (0..90).sum::<usize>() * 99);
+
+        let error = Day04::parse("1\n\n1 2\n3\n").unwrap_err();
+        assert_eq!(error.downcast_ref::<ParseError>().map(|e| e.expected.as_str()), Some("a row of 2 numbers"));
+        Ok(())
+    }
+
+    #[test]
+    fn blank_lines_between_boards() -> Result<()> {
+        let spaced = EXAMPLE.replace("\n\n", "\n\n\n");
+        let input = Day04::parse(&spaced)?;
+        assert_eq!(input.1.len(), 3);
+        assert_eq!(Day04::part1(&input)?, 4512);
+        Ok(())
+    }
+
+    #[test]
+    fn win_patterns() -> Result<()> {
+        let (_, boards) = Day04::parse(EXAMPLE)?;
+        let board = vec![boards[0].clone()];
+        let diagonal = [22, 2, 14, 18, 19];
+        let play = |numbers: &[usize], patterns: &[WinPattern]| first_winner(numbers, &board, patterns);
+
+        assert!(play(&diagonal, WinPattern::STANDARD).is_err());
+        assert_eq!(play(&diagonal, &[WinPattern::Rows, WinPattern::Diagonals])?, (300 - 75) * 19);
+        assert_eq!(play(&[22, 0, 1, 19], &[WinPattern::Corners])?, (300 - 42) * 19);
+
+        let x = [WinPattern::mask("#...#\n.#.#.\n..#..\n.#.#.\n#...#\n")?];
+        assert!(play(&diagonal, &x).is_err());
+        let both = [22, 2, 14, 18, 19, 0, 4, 10, 1];
+        assert_eq!(play(&both, &x)?, 210);
+        // A mask only applies to boards of its own size.
+        assert!(play(&both, &[WinPattern::mask("#.#\n.#.\n#.#\n")?]).is_err());
+
+        let all = EXAMPLE.split("\n\n").nth(1).unwrap().split_whitespace().map(|n| n.parse()).collect::<Result<Vec<usize>, _>>()?;
+        assert!(play(&all[..24], &[WinPattern::Blackout]).is_err());
+        assert_eq!(play(&all, &[WinPattern::Blackout])?, 0);
+
+        assert!(WinPattern::mask("#.\n.x\n").is_err());
+        assert!(WinPattern::mask("..\n..\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn indexed_matches_scanning() -> Result<()> {
+        let (numbers, boards) = Day04::parse(include_str!("../input.txt"))?;
+        let rules = [
+            WinPattern::STANDARD.to_vec(),
+            vec![WinPattern::Diagonals, WinPattern::Corners],
+            vec![WinPattern::Blackout],
+            vec![WinPattern::mask("#...#\n.#.#.\n..#..\n.#.#.\n#...#\n")?],
+        ];
+        for patterns in &rules {
+            let first = first_winner_by_scanning(&numbers, boards.clone(), patterns)?;
+            assert_eq!(first_winner(&numbers, &boards, patterns)?, first);
+            let last = last_winner_by_scanning(&numbers, boards.clone(), patterns)?;
+            assert_eq!(last_winner(&numbers, &boards, patterns)?, last);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_numbers_are_marked_once() -> Result<()> {
+        // One draw marks both 7s, so only the 2 is left when the top row wins.
+        let input = Day04::parse("7,1\n\n7 1\n2 7\n")?;
+        assert_eq!(Day04::part1(&input)?, 2);
+        // Drawing 7 again doesn't take it off the unmarked sum twice.
+        let input = Day04::parse("7,7,1\n\n7 1\n2 3\n")?;
+        assert_eq!(Day04::part1(&input)?, 2 + 3);
+        Ok(())
+    }
+}
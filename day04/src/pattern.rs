@@ -0,0 +1,94 @@
+use anyhow::bail;
+use aoc::{
+    grid::{Grid, Point},
+    ParseError,
+};
+use std::str::FromStr;
+
+/// One way to win a game of bingo.
+///
+/// Every pattern comes down to a set of lines, where a line is a set of
+/// cells that wins once all of them are marked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WinPattern {
+    /// Any full row.
+    Rows,
+    /// Any full column.
+    Columns,
+    /// Either corner-to-corner diagonal. Only square boards have them.
+    Diagonals,
+    /// All four corners.
+    Corners,
+    /// Every cell on the board.
+    Blackout,
+    /// The cells set in the mask, for boards of the mask's size.
+    Mask(Grid<bool>),
+}
+
+impl WinPattern {
+    /// The rules of the puzzle: a full row or column wins.
+    pub const STANDARD: &'static [WinPattern] = &[WinPattern::Rows, WinPattern::Columns];
+
+    /// Parses a mask with one line per row of the board, `#` for the cells
+    /// that have to be marked and `.` for the rest:
+    ///
+    /// ```text
+    /// #...#
+    /// .#.#.
+    /// ..#..
+    /// .#.#.
+    /// #...#
+    /// ```
+    pub fn mask(s: &str) -> Result<Self, ParseError> {
+        let mask = Grid::parse_with(s, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if !mask.values().any(|&cell| cell) {
+            return Err(ParseError::new(s, s, "a mask with at least one `#`"));
+        }
+        Ok(WinPattern::Mask(mask))
+    }
+
+    /// The lines this pattern wins with on a board of `width` by `height`.
+    pub fn lines(&self, width: usize, height: usize) -> Vec<Vec<Point>> {
+        match self {
+            WinPattern::Rows => (0..height).map(|y| (0..width).map(|x| (x, y)).collect()).collect(),
+            WinPattern::Columns => (0..width).map(|x| (0..height).map(|y| (x, y)).collect()).collect(),
+            WinPattern::Diagonals if width == height => vec![
+                (0..width).map(|i| (i, i)).collect(),
+                (0..width).map(|i| (width - 1 - i, i)).collect(),
+            ],
+            WinPattern::Diagonals => vec![],
+            WinPattern::Corners => {
+                let mut corners = vec![(0, 0), (width - 1, 0), (0, height - 1), (width - 1, height - 1)];
+                // A board one cell wide or tall has fewer than four corners.
+                corners.sort_unstable();
+                corners.dedup();
+                vec![corners]
+            }
+            WinPattern::Blackout => vec![(0..height).flat_map(|y| (0..width).map(move |x| (x, y))).collect()],
+            WinPattern::Mask(mask) if (mask.width(), mask.height()) == (width, height) => {
+                vec![mask.iter().filter(|(_, &cell)| cell).map(|(point, _)| point).collect()]
+            }
+            WinPattern::Mask(_) => vec![],
+        }
+    }
+}
+
+/// Parses the name of any pattern but a mask, which needs `WinPattern::mask`.
+impl FromStr for WinPattern {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rows" => Ok(WinPattern::Rows),
+            "columns" => Ok(WinPattern::Columns),
+            "diagonals" => Ok(WinPattern::Diagonals),
+            "corners" => Ok(WinPattern::Corners),
+            "blackout" => Ok(WinPattern::Blackout),
+            _ => bail!("invalid win pattern {}, expected rows, columns, diagonals, corners or blackout", s),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+use std::collections::HashMap;
+use anyhow::Result;
+use aoc::{parse, ParseError, Puzzle};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse::split_once(s, ",")?;
+        Ok(Point { x: parse::number(s, x)?, y: parse::number(s, y)? })
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = parse::split_once(s, " -> ")?;
+        Ok(Line {
+            start: start.parse().map_err(|e: ParseError| e.rebase(s, start))?,
+            end: end.parse().map_err(|e: ParseError| e.rebase(s, end))?,
+        })
+    }
+}
+
+impl Line {
+    fn is_horizontal(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    fn points(&self) -> Vec<Point> {
+        let x1 = self.start.x.min(self.end.x);
+        let x2 = self.start.x.max(self.end.x);
+
+        let y1 = self.start.y.min(self.end.y);
+        let y2 = self.start.y.max(self.end.y);
+
+        if self.start.x == self.end.x {
+            let x = self.start.x;
+            (y1..=y2).map(move |y| Point { x, y }).collect()
+        } else if self.start.y == self.end.y {
+            let y = self.start.y;
+            (x1..=x2).map(move |x| Point { x, y }).collect()
+        } else {
+            assert_eq!(x2 - x1, y2 - y1);
+            let mut points = Vec::new();
+            let mut point = Point { x: self.start.x, y: self.start.y };
+            while point != self.end {
+                points.push(point);
+                if point.x < self.end.x {
+                    point.x += 1;
+                } else {
+                    point.x -= 1;
+                }
+
+                if point.y < self.end.y {
+                    point.y += 1;
+                } else {
+                    point.y -= 1;
+                }
+            }
+            points.push(self.end);
+            points
+        }
+    }
+}
+
+fn part1(lines: &[Line]) -> usize {
+    let mut map: HashMap<Point, usize> = HashMap::new();
+    for line in lines.iter().filter(|line| line.is_horizontal()) {
+            // dbg!(&line, line.points().unwrap().collect::<Vec<_>>());
+        for point in line.points() {
+            *map.entry(point).or_default() += 1;
+        }
+    }
+
+    map.values().filter(|count| **count > 1).count()
+}
+
+fn part2(lines: &[Line]) -> usize {
+    let mut map: HashMap<Point, usize> = HashMap::new();
+    for line in lines.iter() {
+            // dbg!(&line, line.points().unwrap().collect::<Vec<_>>());
+        for point in line.points() {
+            *map.entry(point).or_default() += 1;
+        }
+    }
+
+    map.values().filter(|count| **count > 1).count()
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+
+    type Input<'a> = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        let lines = parse::lines(input, str::parse)?;
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<usize> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<usize> {
+        Ok(part2(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day05::parse(EXAMPLE)?;
+        assert_eq!(Day05::part1(&input)?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day05::parse(EXAMPLE)?;
+        assert_eq!(Day05::part2(&input)?, 12);
+        Ok(())
+    }
+}
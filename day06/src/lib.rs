@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc::{parse, Puzzle};
+
+const BIRTH_PERIOD: usize = 7;
+
+// Part 1: Naive, brute-force solution. Works for 80 days.
+fn part1(fish: &[usize]) -> usize {
+    let mut fish = fish.to_owned();
+
+    for _ in 0..80 {
+        let mut new_fish = Vec::new();
+        for f in &mut fish {
+            if *f > 0 {
+                *f -= 1;
+                continue;
+            }
+
+            *f = BIRTH_PERIOD - 1;
+            new_fish.push((BIRTH_PERIOD - 1) + 2);
+        }
+        fish.extend(new_fish);
+    }
+
+    fish.len()
+}
+
+// Part 2: Naive solution is much too slow and too large for 256 days.
+// Instead bucket each fish into their days so we can bulk process them.
+// We end up with a ~constant amount of work per day.
+fn part2(fish: &[usize]) -> u64 {
+    let mut groups: HashMap<usize, u64> = HashMap::new();
+
+    for f in fish {
+        *groups.entry(*f).or_default() += 1;
+    }
+
+    for _ in 0..256 {
+        let mut new_groups = HashMap::new();
+        for (f, count) in &groups {
+            if *f > 0 {
+                *new_groups.entry(*f - 1).or_default() += count;
+                continue;
+            }
+
+            *new_groups.entry(BIRTH_PERIOD - 1).or_default() += count;
+            *new_groups.entry((BIRTH_PERIOD - 1) + 2).or_default() += count;
+        }
+
+        groups = new_groups;
+    }
+
+    groups.values().sum()
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Lanternfish";
+
+    type Input<'a> = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        let fish = parse::comma_separated(input)?;
+        Ok(fish)
+    }
+
+    fn part1(fish: &Vec<usize>) -> Result<usize> {
+        Ok(part1(fish))
+    }
+
+    fn part2(fish: &Vec<usize>) -> Result<u64> {
+        Ok(part2(fish))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day06::parse(EXAMPLE)?;
+        assert_eq!(Day06::part1(&input)?, 5934);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day06::parse(EXAMPLE)?;
+        assert_eq!(Day06::part2(&input)?, 26984457539);
+        Ok(())
+    }
+}
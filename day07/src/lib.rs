@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use aoc::{parse, Puzzle};
+
+fn part1(positions: &[i32]) -> Result<i32> {
+    let min: i32 = *positions.iter().min().context("no positions")?;
+    let max: i32 = *positions.iter().max().context("no positions")?;
+
+    // O(n^2) sad :(
+    // But it works for this problem :)
+    let minimal_fuel = (min..=max).map(|alignment: i32| {
+        positions.iter().map(|p| (p - alignment).abs()).sum::<i32>()
+    }).min().expect("cannot get here");
+
+    Ok(minimal_fuel)
+}
+
+// Use a cache to essentially memoize computing fuel cost.
+// The solution for part 2 is still slow, but completes in a couple seconds.
+fn compute_fuel(distance: i32, cache: &mut HashMap<i32, i32>) -> i32 {
+    *cache.entry(distance).or_insert_with(|| {
+        (1..=distance).sum::<i32>()
+    })
+}
+
+fn part2(positions: &[i32]) -> Result<i32> {
+    let min: i32 = *positions.iter().min().context("no positions")?;
+    let max: i32 = *positions.iter().max().context("no positions")?;
+
+    let mut cache = HashMap::new();
+
+    // O(n^2) sad :(
+    // But it works for this problem :)
+    let minimal_fuel = (min..=max).map(|alignment: i32| {
+        positions.iter().map(|p| {
+            compute_fuel((p - alignment).abs(), &mut cache)
+        }).sum::<i32>()
+    }).min().expect("cannot get here");
+
+    Ok(minimal_fuel)
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+
+    type Input<'a> = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        let positions = parse::comma_separated(input)?;
+        Ok(positions)
+    }
+
+    fn part1(positions: &Vec<i32>) -> Result<i32> {
+        part1(positions)
+    }
+
+    fn part2(positions: &Vec<i32>) -> Result<i32> {
+        part2(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day07::parse(EXAMPLE)?;
+        assert_eq!(Day07::part1(&input)?, 37);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day07::parse(EXAMPLE)?;
+        assert_eq!(Day07::part2(&input)?, 168);
+        Ok(())
+    }
+}
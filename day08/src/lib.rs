@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+use std::{collections::{BTreeSet}, convert::TryFrom};
+
+type Display = BTreeSet<char>;
+
+#[derive(Clone, Debug)]
+pub struct Line {
+    signals: Vec<Display>,
+    output: Vec<Display>,
+}
+
+impl TryFrom<&str> for Line {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (signals, output) = parse::split_once(s, "|")?;
+        if let Some(c) = s.matches(|c: char| !matches!(c, 'a'..='g' | ' ' | '|')).next() {
+            return Err(ParseError::new(s, c, "a segment between `a` and `g`"));
+        }
+
+        let signals = signals.split_whitespace().map(|s| s.chars().collect()).collect();
+        let output = output.split_whitespace().map(|s| s.chars().collect()).collect();
+
+        Ok(Line { signals, output })
+    }
+}
+
+impl Line {
+    fn decode_output(&self) -> Result<usize> {
+        let mut mapping: [Option<&Display>; 10] = [None; 10];
+
+        // 1
+        mapping[1] = Some(self.signals.iter().find(|d| d.len() == 2).context("unable to find 1")?);
+
+        // 4
+        mapping[4] = Some(self.signals.iter().find(|d| d.len() == 4).context("unable to find 4")?);
+
+        // 7
+        mapping[7] = Some(self.signals.iter().find(|d| d.len() == 3).context("unable to find 7")?);
+
+        // 8
+        mapping[8] = Some(self.signals.iter().find(|d| d.len() == 7).context("unable to find 8")?);
+
+        // 3
+        mapping[3] = Some(self.signals.iter().filter(|d| d.len() == 5).find(|d| d.intersection(mapping[1].unwrap()).count() == 2).context("unable to find 3")?);
+
+        for d in self.signals.iter().filter(|d| d.len() == 5) {
+            if (d.intersection(mapping[1].unwrap())).count() == 2 {
+                mapping[3] = Some(d);
+            } else if (d.intersection(mapping[4].unwrap())).count() == 2 {
+                mapping[2] = Some(d);
+            } else {
+                mapping[5] = Some(d);
+            }
+        }
+
+        for d in self.signals.iter().filter(|d| d.len() == 6) {
+            if (d.intersection(mapping[1].unwrap())).count() == 1 {
+                mapping[6] = Some(d);
+            } else if (d.intersection(mapping[4].unwrap())).count() == 4 {
+                mapping[9] = Some(d);
+            } else {
+                mapping[0] = Some(d);
+            }
+        }
+
+        Ok(self.output.iter().fold(0, |acc, d| {
+            let (num, _) = mapping.iter().enumerate().find(|(_, a)| **a == Some(d)).unwrap();
+            (acc * 10) + num
+        }))
+    }
+}
+
+fn part1(lines: &[Line]) -> usize {
+    // 1, 4, 7, 8 have unique count of segments enabled
+    lines.iter().map(|l| l.output.iter().filter(|o| o.len() == 2 || o.len() == 4 || o.len() == 3 || o.len() == 7).count()).sum()
+}
+
+fn part2(lines: &[Line]) -> Result<usize> {
+    let outputs = lines.iter().map(|l| l.decode_output()).collect::<Result<Vec<_>>>()?;
+    Ok(outputs.iter().sum())
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+
+    type Input<'a> = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        let lines = parse::lines(input, Line::try_from)?;
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<usize> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<usize> {
+        part2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day08::parse(EXAMPLE)?;
+        assert_eq!(Day08::part1(&input)?, 26);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day08::parse(EXAMPLE)?;
+        assert_eq!(Day08::part2(&input)?, 61229);
+        Ok(())
+    }
+}
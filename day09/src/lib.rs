@@ -0,0 +1,112 @@
+use anyhow::Result;
+use aoc::{grid::Point, Grid, ParseError, Puzzle};
+use std::{collections::HashSet, str::FromStr};
+
+pub struct HeightMap {
+    heights: Grid<u32>,
+}
+
+impl FromStr for HeightMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse_digits(s)?;
+
+        Ok(HeightMap { heights })
+    }
+}
+
+impl HeightMap {
+    fn low_points(&self) -> Vec<Point> {
+        self.heights.iter()
+            .filter(|(point, entry)| {
+                self.heights.neighbors4(*point).all(|other| self.heights[other] > **entry)
+            })
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    fn largest_basin(&self) -> usize {
+        let low_points = self.low_points();
+        let mut basins = Vec::new();
+
+        for root in &low_points {
+            let mut closed_list = HashSet::new();
+            let mut open_list = vec![*root];
+
+            while let Some(loc) = open_list.pop() {
+                if self.heights[loc] == 9 {
+                    continue;
+                }
+
+                closed_list.insert(loc);
+                open_list.extend(
+                    self.heights.neighbors4(loc)
+                        .filter(|d| !closed_list.contains(d))
+                );
+            }
+
+            basins.push((closed_list.len(), closed_list));
+        }
+
+        basins.sort_by(|a, b| { b.0.cmp(&a.0) });
+
+        basins[0..3].iter().fold(1, |acc, (size, _)| acc * size)
+    }
+}
+
+fn part1(height_map: &HeightMap) -> u32 {
+    let low_points = height_map.low_points();
+    low_points.iter().map(|point| height_map.heights[*point] + 1).sum()
+}
+
+fn part2(height_map: &HeightMap) -> usize {
+    height_map.largest_basin()
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+
+    type Input<'a> = HeightMap;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<HeightMap> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(height_map: &HeightMap) -> Result<u32> {
+        Ok(part1(height_map))
+    }
+
+    fn part2(height_map: &HeightMap) -> Result<usize> {
+        Ok(part2(height_map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day09::parse(EXAMPLE)?;
+        assert_eq!(Day09::part1(&input)?, 15);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day09::parse(EXAMPLE)?;
+        assert_eq!(Day09::part2(&input)?, 1134);
+        Ok(())
+    }
+}
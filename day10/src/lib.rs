@@ -0,0 +1,130 @@
+use anyhow::{anyhow, Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+
+fn first_error(line: &str) -> Result<Option<char>> {
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '(' | '<' | '{' | '[' => stack.push(c),
+            ')' | '>' | '}' | ']' => {
+                let opener = stack.pop().context("unexpected closer")?;
+                match (opener, c) {
+                    ('(', ')') | ('<', '>') | ('{', '}') | ('[', ']') => {},
+                    _ => return Ok(Some(c)),
+                }
+            },
+            _ => return Err(anyhow!("unexpected input")),
+        }
+    }
+
+    Ok(None)
+}
+
+fn score_line(line: &str) -> Result<Option<usize>> {
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '(' | '<' | '{' | '[' => stack.push(c),
+            ')' | '>' | '}' | ']' => {
+                let opener = stack.pop().context("unexpected closer")?;
+                match (opener, c) {
+                    ('(', ')') | ('<', '>') | ('{', '}') | ('[', ']') => {},
+                    _ => return Ok(None),
+                }
+            },
+            _ => return Err(anyhow!("unexpected input")),
+        }
+    }
+
+    let mut score = 0;
+    for c in stack.iter().rev() {
+        score *= 5;
+        match c {
+            '(' => score += 1,
+            '[' => score += 2,
+            '{' => score += 3,
+            '<' => score += 4,
+            _ => return Err(anyhow!("unexpected input")),
+        }
+    }
+
+    Ok(Some(score))
+}
+
+fn part1(lines: &[&str]) -> Result<usize> {
+    let mut score = 0;
+    for line in lines {
+        match first_error(line)? {
+            Some(')') => score += 3,
+            Some(']') => score += 57,
+            Some('}') => score += 1197,
+            Some('>') => score += 25137,
+            _ => {},
+        }
+    }
+
+    Ok(score)
+}
+
+fn part2(lines: &[&str]) -> Result<usize> {
+    let scores = lines.iter().map(|l| score_line(l)).collect::<Result<Vec<_>>>()?;
+    let mut scores = scores.iter().filter_map(|s| *s).collect::<Vec<_>>();
+    scores.sort();
+
+    Ok(scores[scores.len() / 2])
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+
+    type Input<'a> = Vec<&'a str>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        let lines = parse::lines(input, |line| {
+            match line.matches(|c| !matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '<' | '>')).next() {
+                Some(c) => Err(ParseError::new(line, c, "a bracket")),
+                None => Ok(line),
+            }
+        })?;
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<&str>) -> Result<usize> {
+        part1(lines)
+    }
+
+    fn part2(lines: &Vec<&str>) -> Result<usize> {
+        part2(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day10::parse(EXAMPLE)?;
+        assert_eq!(Day10::part1(&input)?, 26397);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day10::parse(EXAMPLE)?;
+        assert_eq!(Day10::part2(&input)?, 288957);
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use aoc::{Grid, Puzzle};
+
+/// Runs one step, returning how many octopuses flashed.
+fn step(grid: &mut Grid<u32>) -> usize {
+    for entry in grid.values_mut() {
+        *entry += 1;
+    }
+
+    // Note: At this point, no octopuses have an energy level of 0.
+    // We will use 0 to mark the octopus has flashed once this iteration.
+
+    let mut flash_list = grid.iter()
+        .filter(|(_, entry)| **entry > 9)
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+    let mut flash_count = 0;
+
+    while let Some(pos) = flash_list.pop() {
+        // Already flashed
+        if grid[pos] == 0 {
+            continue;
+        }
+
+        flash_count += 1;
+
+        grid[pos] = 0;
+        let neighbors = grid.neighbors8(pos).collect::<Vec<_>>();
+
+        for neighbor in neighbors {
+            // Already flashed
+            if grid[neighbor] == 0 {
+                continue;
+            }
+
+            grid[neighbor] += 1;
+            if grid[neighbor] > 9 {
+                flash_list.push(neighbor);
+            }
+        }
+    }
+
+    flash_count
+}
+
+fn part1(mut grid: Grid<u32>) -> usize {
+    (0..100).map(|_| step(&mut grid)).sum()
+}
+
+fn part2(mut grid: Grid<u32>) -> usize {
+    for i in 1.. {
+        step(&mut grid);
+
+        if grid.values().all(|e| *e == 0) {
+            return i;
+        }
+    }
+
+    0
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+
+    type Input<'a> = Grid<u32>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>> {
+        Ok(Grid::parse_digits(input)?)
+    }
+
+    fn part1(grid: &Grid<u32>) -> Result<usize> {
+        Ok(part1(grid.clone()))
+    }
+
+    fn part2(grid: &Grid<u32>) -> Result<usize> {
+        Ok(part2(grid.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day11::parse(EXAMPLE)?;
+        assert_eq!(Day11::part1(&input)?, 1656);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day11::parse(EXAMPLE)?;
+        assert_eq!(Day11::part2(&input)?, 195);
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use anyhow::Result;
+use aoc::{parse, ParseError, Puzzle};
+use std::{collections::{HashMap, HashSet}, convert::TryFrom, fmt};
+
+const START: &str = "start";
+const END: &str = "end";
+
+type Node<'a> = &'a str;
+
+#[derive(Clone)]
+struct Path<'a>(Vec<Node<'a>>);
+
+impl<'a> fmt::Debug for Path<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.0.iter().enumerate() {
+            write!(f, "{}", node)?;
+            if i != self.0.len() - 1 {
+                write!(f, "->")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<'a> {
+    edges: HashMap<Node<'a>, HashSet<Node<'a>>>,
+}
+
+impl<'a> TryFrom<&'a str> for Graph<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let edges: Vec<(Node, Node)> = parse::lines(s, |line| parse::split_once(line, "-"))?;
+
+        let edges = edges.iter().fold(HashMap::<Node, HashSet<Node>>::new(), |mut map, (from, to)| {
+            if *to != START {
+                map.entry(*from).or_default().insert(to);
+            }
+            if *from != START {
+                map.entry(*to).or_default().insert(from);
+            }
+            map
+        });
+
+        Ok(Graph { edges })
+    }
+}
+
+fn find_paths_1<'a>(graph: &Graph<'a>, path: Path<'a>) -> Vec<Path<'a>> {
+    let current = path.0.last().expect("path should never be empty");
+    if *current == END {
+        return vec![path];
+    }
+
+    let options = match graph.edges.get(current) {
+        Some(options) => options,
+        None => return Vec::new(),
+    };
+
+    options.iter()
+        .filter(|next| {
+            next.to_uppercase() == **next || !path.0.contains(next)
+        })
+        .flat_map(|next| {
+            let mut new_path = path.clone();
+            new_path.0.push(next);
+            find_paths_1(graph, new_path)
+        })
+        .collect()
+}
+
+fn part1(graph: &Graph) -> usize {
+    find_paths_1(graph, Path(vec![START])).len()
+}
+
+fn find_paths_2<'a>(graph: &Graph<'a>, path: Path<'a>, small_twice: bool) -> Vec<Path<'a>> {
+    let current = path.0.last().expect("path should never be empty");
+    if *current == END {
+        return vec![path];
+    }
+
+    let options = match graph.edges.get(current) {
+        Some(options) => options,
+        None => return Vec::new(),
+    };
+
+    options.iter()
+        .filter(|next| {
+            !small_twice || next.to_uppercase() == **next || !path.0.contains(next)
+        })
+        .flat_map(|next| {
+            let mut new_path = path.clone();
+            new_path.0.push(next);
+            let small_twice = small_twice || (next.to_lowercase() == **next && path.0.contains(next));
+            find_paths_2(graph, new_path, small_twice)
+        })
+        .collect()
+}
+
+fn part2(graph: &Graph) -> usize {
+    find_paths_2(graph, Path(vec![START]), false).len()
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+
+    type Input<'a> = Graph<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Graph<'_>> {
+        Ok(input.try_into()?)
+    }
+
+    fn part1(graph: &Graph<'_>) -> Result<usize> {
+        Ok(part1(graph))
+    }
+
+    fn part2(graph: &Graph<'_>) -> Result<usize> {
+        Ok(part2(graph))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day12::parse(EXAMPLE)?;
+        assert_eq!(Day12::part1(&input)?, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day12::parse(EXAMPLE)?;
+        assert_eq!(Day12::part2(&input)?, 36);
+        Ok(())
+    }
+
+    const LARGER_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+
+    const EVEN_LARGER_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn part1_larger_examples() -> Result<()> {
+        assert_eq!(Day12::part1(&Day12::parse(LARGER_EXAMPLE)?)?, 19);
+        assert_eq!(Day12::part1(&Day12::parse(EVEN_LARGER_EXAMPLE)?)?, 226);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_larger_examples() -> Result<()> {
+        assert_eq!(Day12::part2(&Day12::parse(LARGER_EXAMPLE)?)?, 103);
+        assert_eq!(Day12::part2(&Day12::parse(EVEN_LARGER_EXAMPLE)?)?, 3509);
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::{grid::Point, parse, ParseError, Puzzle};
+use std::{collections::HashSet, fmt, str::FromStr};
+
+/// The marked points on the paper. They're kept sparse, since the paper can
+/// be far bigger than the handful of marks on it.
+#[derive(Debug, Clone)]
+pub struct Board {
+    marks: HashSet<Point>,
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let marks = parse::lines(s, |line| {
+            let (x, y) = parse::split_once(line, ",")?;
+            Ok((
+                parse::number(line, x)?,
+                parse::number(line, y)?,
+            ))
+        })?;
+        Ok(Board { marks: marks.into_iter().collect() })
+    }
+}
+
+/// The most cells `part2` will render, far more than any folded code needs.
+const MAX_RENDERED_CELLS: usize = 1 << 20;
+
+impl Board {
+    /// The furthest column and row with a mark in them.
+    fn max_corner(&self) -> Point {
+        let x_max = self.marks.iter().map(|(x, _)| *x).max().unwrap_or(0);
+        let y_max = self.marks.iter().map(|(_, y)| *y).max().unwrap_or(0);
+        (x_max, y_max)
+    }
+
+    /// The width and height from the top left corner of the paper to the
+    /// furthest mark, or `None` if that doesn't fit in a `usize`.
+    fn extent(&self) -> Option<(usize, usize)> {
+        let (x_max, y_max) = self.max_corner();
+        Some((x_max.checked_add(1)?, y_max.checked_add(1)?))
+    }
+}
+
+/// Renders the marks from the top left corner of the paper to the furthest
+/// mark, a row at a time, padding every row to the width of the widest.
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x_max, y_max) = self.max_corner();
+
+        let mut marks = self.marks.iter().map(|&(x, y)| (y, x)).collect::<Vec<_>>();
+        marks.sort_unstable();
+        let mut marks = marks.into_iter().peekable();
+
+        for y in 0..=y_max {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            // The column of the last mark written on this row.
+            let mut last = None;
+            while let Some((_, x)) = marks.next_if(|(row, _)| *row == y) {
+                let gap = x - last.map_or(0, |last: usize| last + 1);
+                write!(f, "{:gap$}#", "", gap = gap)?;
+                last = Some(x);
+            }
+            match last {
+                Some(last) => write!(f, "{:pad$}", "", pad = x_max - last)?,
+                None => write!(f, " {:pad$}", "", pad = x_max)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let s = line.strip_prefix("fold along ")
+            .ok_or_else(|| ParseError::new(line, line, "`fold along`"))?;
+        let (axis, pos) = parse::split_once(s, "=").map_err(|e| e.rebase(line, s))?;
+        let pos: usize = parse::number(line, pos)?;
+
+        match axis {
+            "x" => Ok(Fold::X(pos)),
+            "y" => Ok(Fold::Y(pos)),
+            _ => Err(ParseError::new(line, axis, "`x` or `y`")),
+        }
+    }
+}
+
+fn do_fold(board: &Board, fold: &Fold) -> Result<Board> {
+    // The mirror image of `pos` across `axis`, if it lands on the paper.
+    let reflect = |axis: usize, pos: usize| axis.checked_mul(2).and_then(|end| end.checked_sub(pos));
+
+    let marks = board.marks.iter().map(|&(x, y)| {
+        let point = match fold {
+            Fold::X(axis) if x > *axis => reflect(*axis, x).map(|x| (x, y)),
+            Fold::Y(axis) if y > *axis => reflect(*axis, y).map(|y| (x, y)),
+            _ => Some((x, y)),
+        };
+        point.with_context(|| format!("mark at {},{} is not folded onto the paper by {:?}", x, y, fold))
+    }).collect::<Result<_>>()?;
+
+    Ok(Board { marks })
+}
+
+fn part1(board: &Board, folds: &[Fold]) -> Result<usize> {
+    let fold = folds.first().context("Expected at least one fold")?;
+    let board = do_fold(board, fold)?;
+
+    Ok(board.marks.len())
+}
+
+fn part2(board: &Board, folds: &[Fold]) -> Result<Board> {
+    let mut board = board.clone();
+    for fold in folds {
+        board = do_fold(&board, fold)?;
+    }
+
+    if board.marks.is_empty() {
+        return Err(anyhow!("Expected at least one point"));
+    }
+
+    match board.extent() {
+        Some((width, height)) if width.checked_mul(height).is_some_and(|cells| cells <= MAX_RENDERED_CELLS) => {}
+        Some((width, height)) => bail!("the folded paper is {}x{}, too large to render", width, height),
+        None => bail!("the folded paper is too large to render"),
+    }
+
+    Ok(board)
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+
+    /// The marked paper and the folds to apply to it.
+    type Input<'a> = (Board, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = Board;
+
+    fn parse(input: &str) -> Result<(Board, Vec<Fold>)> {
+        let (marks, folds) = parse::split_once(input, "\n\n")?;
+        let board: Board = marks.parse().map_err(|e: ParseError| e.rebase(input, marks))?;
+        let folds = parse::lines(folds, Fold::from_str).map_err(|e| e.rebase(input, folds))?;
+        Ok((board, folds))
+    }
+
+    fn part1((board, folds): &(Board, Vec<Fold>)) -> Result<usize> {
+        part1(board, folds)
+    }
+
+    fn part2((board, folds): &(Board, Vec<Fold>)) -> Result<Board> {
+        part2(board, folds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day13::parse(EXAMPLE)?;
+        assert_eq!(Day13::part1(&input)?, 17);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day13::parse(EXAMPLE)?;
+        let expected = "\
+#####
+#   #
+#   #
+#   #
+#####";
+        assert_eq!(Day13::part2(&input)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn marks_far_apart() -> Result<()> {
+        let input = Day13::parse("99999999999999999,99999999999999\n100000,100000\n1,0\n\nfold along x=5\n")?;
+        assert!(Day13::part1(&input).is_err());
+        let input = Day13::parse("100000,100000\n1,0\n\nfold along x=50000\nfold along y=50000\n")?;
+        assert_eq!(Day13::part1(&input)?, 2);
+        assert_eq!(Day13::part2(&input)?.to_string(), "##");
+
+        // Valid folds can still leave too much paper to draw.
+        let input = Day13::parse("1000000000,1000000000\n0,0\n\nfold along x=999999999\n")?;
+        assert_eq!(Day13::part1(&input)?, 2);
+        assert_eq!(Day13::part2(&input).unwrap_err().to_string(), "the folded paper is 999999999x1000000001, too large to render");
+        let board: Board = format!("{},0\n0,1", usize::MAX).parse()?;
+        assert!(part2(&board, &[]).is_err());
+
+        let board: Board = "3,0\n0,2\n".parse()?;
+        assert_eq!(board.to_string(), "   #\n    \n#   ");
+        Ok(())
+    }
+}
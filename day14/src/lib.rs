@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use aoc::{parse, ParseError, Puzzle};
+use std::collections::HashMap;
+
+pub type InsertionRules = HashMap<(char, char), char>;
+
+fn apply_rules(template: &[char], rules: &InsertionRules) -> Result<Vec<char>> {
+    let new_elements = template.windows(2).map(|pair| {
+        let key = (pair[0], pair[1]);
+        rules.get(&key).context("expected insertion rule")
+    }).collect::<Result<Vec<_>>>()?;
+
+    let mut result = Vec::new();
+    for (i, c) in template.iter().enumerate() {
+        result.push(*c);
+        if let Some(new_elem) = new_elements.get(i) {
+            result.push(**new_elem);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Applies the rules `steps` times by building the whole polymer, then returns
+/// the difference between the most and least common element counts.
+pub fn expand_polymer(template: &[char], rules: &InsertionRules, steps: usize) -> Result<usize> {
+    let mut template = template.to_owned();
+    for _ in 0..steps {
+        template = apply_rules(&template, rules)?;
+    }
+
+    let buckets: HashMap<char, usize> = template.iter().fold(HashMap::new(), |mut acc, elem| {
+        *acc.entry(*elem).or_default() += 1;
+        acc
+    });
+
+    Ok(buckets.values().max().context("expected values")? - buckets.values().min().context("expected values")?)
+}
+
+/// Same as `expand_polymer`, but only tracks how often each pair occurs so the
+/// work per step doesn't grow with the polymer.
+pub fn count_pairs(template: &[char], rules: &InsertionRules, steps: usize) -> Result<usize> {
+    let mut pairs: HashMap<(char, char), usize> = template.windows(2).map(|w| (w[0], w[1]))
+        .fold(HashMap::new(), |mut acc, key| {
+            *acc.entry(key).or_default() += 1;
+            acc
+        });
+
+    // The idea is to keep track of pairs instead of the whole string
+    // This lets us bulk process each pair and provides an ubber bound on the amount
+    // of work done per step, since there is only a finite number of element pair combinations.
+    for _ in 0..steps {
+        let mut new_pairs = HashMap::new();
+        for (pair, count) in &pairs {
+            let out = rules.get(pair).context("Expected rule")?;
+            *new_pairs.entry((pair.0, *out)).or_default() += count;
+            *new_pairs.entry((*out, pair.1)).or_default() += count;
+        }
+        pairs = new_pairs;
+    }
+
+    // Pairs overlap, so only count the first element of each one. That misses
+    // the last element of the polymer, which never changes from the template.
+    let mut buckets: HashMap<char, usize> = pairs.iter().fold(HashMap::new(), |mut acc, ((a, _), count)| {
+        *acc.entry(*a).or_default() += count;
+        acc
+    });
+    *buckets.entry(*template.last().context("expected template")?).or_default() += 1;
+
+    Ok(buckets.values().max().context("expected values")? - buckets.values().min().context("expected values")?)
+}
+
+/// The puzzle input that ships with this day.
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+
+    /// The polymer template and the pair insertion rules.
+    type Input<'a> = (Vec<char>, InsertionRules);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<char>, InsertionRules)> {
+        let (template, rules) = parse::split_once(input, "\n\n")?;
+        let template = template.chars().collect::<Vec<_>>();
+        let rules = parse::lines(rules, |line| {
+            let (pair, out) = parse::split_once(line, " -> ")?;
+            let key = match pair.chars().collect::<Vec<_>>()[..] {
+                [a, b] => (a, b),
+                _ => return Err(ParseError::new(line, pair, "a pair of elements")),
+            };
+            let out = match out.chars().collect::<Vec<_>>()[..] {
+                [out] => out,
+                _ => return Err(ParseError::new(line, out, "a single element")),
+            };
+            Ok((key, out))
+        }).map_err(|e| e.rebase(input, rules))?;
+        Ok((template, rules.into_iter().collect()))
+    }
+
+    fn part1((template, rules): &(Vec<char>, InsertionRules)) -> Result<usize> {
+        expand_polymer(template, rules, 10)
+    }
+
+    fn part2((template, rules): &(Vec<char>, InsertionRules)) -> Result<usize> {
+        count_pairs(template, rules, 40)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input_small.txt");
+
+    #[test]
+    fn part1_example() -> Result<()> {
+        let input = Day14::parse(EXAMPLE)?;
+        assert_eq!(Day14::part1(&input)?, 1588);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_example() -> Result<()> {
+        let input = Day14::parse(EXAMPLE)?;
+        assert_eq!(Day14::part2(&input)?, 2188189693529);
+        Ok(())
+    }
+}
@@ -5,6 +5,5 @@
         pkgs.cargo
         pkgs.cargo-edit
         pkgs.rust-analyzer
-        pkgs.fzy
     ];
 }
\ No newline at end of file
@@ -0,0 +1,195 @@
+use aoc::Puzzle;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use day03::{Day03, TieBreak};
+use day04::{Day04, WinPattern};
+use day05::Day05;
+use day06::Day06;
+use day14::Day14;
+
+/// Benchmarks parsing and both parts of a day against its real input.
+fn bench_day<P: Puzzle>(c: &mut Criterion, input_path: &str) {
+    let input = aoc::read_input(input_path).expect("failed to read input");
+    let parsed = P::parse(&input).expect("failed to parse input");
+
+    let mut group = c.benchmark_group(format!("day{:02}", P::DAY));
+    group.bench_function("parse", |b| b.iter(|| P::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| P::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| P::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, day01::INPUT_PATH);
+    bench_day::<day02::Day02>(c, day02::INPUT_PATH);
+    bench_day::<day03::Day03>(c, day03::INPUT_PATH);
+    bench_day::<day04::Day04>(c, day04::INPUT_PATH);
+    bench_day::<day05::Day05>(c, day05::INPUT_PATH);
+    bench_day::<day06::Day06>(c, day06::INPUT_PATH);
+    bench_day::<day07::Day07>(c, day07::INPUT_PATH);
+    bench_day::<day08::Day08>(c, day08::INPUT_PATH);
+    bench_day::<day09::Day09>(c, day09::INPUT_PATH);
+    bench_day::<day10::Day10>(c, day10::INPUT_PATH);
+    bench_day::<day11::Day11>(c, day11::INPUT_PATH);
+    bench_day::<day12::Day12>(c, day12::INPUT_PATH);
+    bench_day::<day13::Day13>(c, day13::INPUT_PATH);
+    bench_day::<day14::Day14>(c, day14::INPUT_PATH);
+}
+
+/// Small xorshift generator so synthetic inputs are the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
+
+/// `count` horizontal, vertical and diagonal vents on a 1000x1000 floor.
+fn synthetic_vents(count: usize) -> String {
+    let mut rng = Rng(0x5eed);
+    let mut input = String::new();
+    for _ in 0..count {
+        // Starting in the middle keeps every line on the floor.
+        let (x1, y1) = (300 + rng.below(400) as i64, 300 + rng.below(400) as i64);
+        let length = 1 + rng.below(299) as i64;
+        let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1)][rng.below(4) as usize];
+        input += &format!("{},{} -> {},{}\n", x1, y1, x1 + dx * length, y1 + dy * length);
+    }
+    input
+}
+
+/// `count` random binary numbers of `width` bits, one per line.
+fn synthetic_report(count: usize, width: usize) -> String {
+    let mut rng = Rng(0xd1a6);
+    let mut input = String::new();
+    for _ in 0..count {
+        input.extend((0..width).map(|_| if rng.below(2) == 1 { '1' } else { '0' }));
+        input.push('\n');
+    }
+    input
+}
+
+/// The numbers 0 to 99 in a random order, then `count` 5x5 boards of 25
+/// different numbers each.
+fn synthetic_bingo(count: usize) -> String {
+    let mut rng = Rng(0xb1960);
+    let shuffled = |rng: &mut Rng| {
+        let mut numbers = (0..100).collect::<Vec<u64>>();
+        for i in (1..numbers.len()).rev() {
+            numbers.swap(i, rng.below(i as u64 + 1) as usize);
+        }
+        numbers
+    };
+    let draws = shuffled(&mut rng).iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",");
+    let mut input = draws + "\n";
+    for _ in 0..count {
+        let numbers = shuffled(&mut rng);
+        input.push('\n');
+        for row in numbers[..25].chunks(5) {
+            input += &row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>().join(" ");
+            input.push('\n');
+        }
+    }
+    input
+}
+
+fn synthetic_fish(count: usize) -> String {
+    let mut rng = Rng(0xf154);
+    (0..count).map(|_| (1 + rng.below(5)).to_string()).collect::<Vec<_>>().join(",")
+}
+
+fn scaled(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day03_life_support");
+    group.sample_size(10);
+    for count in [10_000, 100_000, 1_000_000] {
+        let report = Day03::parse(&synthetic_report(count, 24)).unwrap();
+        group.bench_with_input(BenchmarkId::new("filtering", count), &report, |b, (numbers, width)| {
+            b.iter(|| day03::life_support_by_filtering(numbers, *width, TieBreak::PreferOne))
+        });
+        group.bench_with_input(BenchmarkId::new("partitioning", count), &report, |b, (numbers, width)| {
+            b.iter(|| day03::life_support(numbers, *width, TieBreak::PreferOne))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day03_column_counts");
+    group.sample_size(10);
+    for count in [10_000, 100_000, 1_000_000] {
+        let report = Day03::parse(&synthetic_report(count, 24)).unwrap();
+        group.bench_with_input(BenchmarkId::new("folding", count), &report, |b, (numbers, width)| {
+            b.iter(|| day03::column_counts_by_folding(numbers, *width))
+        });
+        group.bench_with_input(BenchmarkId::new("bit_sliced", count), &report, |b, (numbers, width)| {
+            b.iter(|| day03::column_counts(numbers, *width))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day04_winners");
+    group.sample_size(10);
+    for count in [1_000, 10_000, 100_000] {
+        let (numbers, boards) = Day04::parse(&synthetic_bingo(count)).unwrap();
+        group.bench_with_input(BenchmarkId::new("first_scanning", count), &boards, |b, boards| {
+            // The scanning versions take the boards by value, so they get a copy made outside the timing.
+            b.iter_batched(
+                || boards.clone(),
+                |boards| day04::first_winner_by_scanning(&numbers, boards, WinPattern::STANDARD),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("first_indexed", count), &boards, |b, boards| {
+            b.iter(|| day04::first_winner(&numbers, boards, WinPattern::STANDARD))
+        });
+        group.bench_with_input(BenchmarkId::new("last_scanning", count), &boards, |b, boards| {
+            b.iter_batched(
+                || boards.clone(),
+                |boards| day04::last_winner_by_scanning(&numbers, boards, WinPattern::STANDARD),
+                BatchSize::LargeInput,
+            )
+        });
+        group.bench_with_input(BenchmarkId::new("last_indexed", count), &boards, |b, boards| {
+            b.iter(|| day04::last_winner(&numbers, boards, WinPattern::STANDARD))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day05_scaled");
+    group.sample_size(10);
+    for count in [500, 2_000, 8_000] {
+        let lines = Day05::parse(&synthetic_vents(count)).unwrap();
+        group.bench_with_input(BenchmarkId::new("part1", count), &lines, |b, lines| b.iter(|| Day05::part1(lines)));
+        group.bench_with_input(BenchmarkId::new("part2", count), &lines, |b, lines| b.iter(|| Day05::part2(lines)));
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day06_scaled");
+    group.sample_size(10);
+    for count in [300, 600, 1_200] {
+        let fish = Day06::parse(&synthetic_fish(count)).unwrap();
+        group.bench_with_input(BenchmarkId::new("part1", count), &fish, |b, fish| b.iter(|| Day06::part1(fish)));
+        group.bench_with_input(BenchmarkId::new("part2", count), &fish, |b, fish| b.iter(|| Day06::part2(fish)));
+    }
+    group.finish();
+
+    let input = aoc::read_input(day14::INPUT_PATH).unwrap();
+    let (template, rules) = Day14::parse(&input).unwrap();
+    let mut group = c.benchmark_group("day14_steps");
+    group.sample_size(10);
+    for steps in [5, 10, 15] {
+        group.bench_with_input(BenchmarkId::new("expand_polymer", steps), &steps, |b, &steps| {
+            b.iter(|| day14::expand_polymer(&template, &rules, steps))
+        });
+    }
+    for steps in [10, 20, 40] {
+        group.bench_with_input(BenchmarkId::new("count_pairs", steps), &steps, |b, &steps| {
+            b.iter(|| day14::count_pairs(&template, &rules, steps))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, days, scaled);
+criterion_main!(benches);
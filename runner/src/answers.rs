@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use aoc::Part;
+use std::fs;
+use toml::{Table, Value};
+
+/// The answers accepted for the inputs that ship with each day.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Known answers, one table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = "1709"
+/// part2 = "1761"
+/// ```
+pub struct Answers(Table);
+
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("failed to read answers file {}", path))?;
+        let table = text.parse::<Table>().with_context(|| format!("invalid answers file {}", path))?;
+        Ok(Answers(table))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        let day = self.0.get(&format!("day{:02}", day))?;
+        match day.get(format!("part{}", part))? {
+            Value::String(answer) => Some(answer.clone()),
+            Value::Integer(answer) => Some(answer.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if normalize(&expected) == normalize(answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+// Pictures like day 13's are padded with spaces that editors like to strip,
+// so trailing whitespace on each line doesn't count.
+fn normalize(answer: &str) -> String {
+    answer.trim().lines().map(str::trim_end).collect::<Vec<_>>().join("\n")
+}
@@ -0,0 +1,26 @@
+use anyhow::{Context, Result};
+use aoc::Solution;
+
+/// Every day that has been solved so far, in order.
+pub fn all() -> Vec<Solution> {
+    vec![
+        Solution::new::<day01::Day01>(day01::INPUT_PATH),
+        Solution::new::<day02::Day02>(day02::INPUT_PATH),
+        Solution::new::<day03::Day03>(day03::INPUT_PATH),
+        Solution::new::<day04::Day04>(day04::INPUT_PATH),
+        Solution::new::<day05::Day05>(day05::INPUT_PATH),
+        Solution::new::<day06::Day06>(day06::INPUT_PATH),
+        Solution::new::<day07::Day07>(day07::INPUT_PATH),
+        Solution::new::<day08::Day08>(day08::INPUT_PATH),
+        Solution::new::<day09::Day09>(day09::INPUT_PATH),
+        Solution::new::<day10::Day10>(day10::INPUT_PATH),
+        Solution::new::<day11::Day11>(day11::INPUT_PATH),
+        Solution::new::<day12::Day12>(day12::INPUT_PATH),
+        Solution::new::<day13::Day13>(day13::INPUT_PATH),
+        Solution::new::<day14::Day14>(day14::INPUT_PATH),
+    ]
+}
+
+pub fn find(day: u8) -> Result<Solution> {
+    all().into_iter().find(|s| s.day == day).with_context(|| format!("day {} has not been solved", day))
+}
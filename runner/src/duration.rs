@@ -0,0 +1,57 @@
+use anyhow::{bail, Context, Result};
+use std::time::Duration;
+
+/// Formats `duration` with a unit that keeps the number readable.
+pub fn format(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.1}µs", secs * 1e6)
+    }
+}
+
+/// Parses durations like `1s`, `250ms` or `10us`.
+pub fn parse(s: &str) -> Result<Duration> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.').context("expected a unit (s, ms, us or ns)")?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().with_context(|| format!("invalid duration {}", s))?;
+
+    let secs = match unit {
+        "s" => value,
+        "ms" => value / 1e3,
+        "us" | "µs" => value / 1e6,
+        "ns" => value / 1e9,
+        _ => bail!("unknown unit {}, expected s, ms, us or ns", unit),
+    };
+
+    Duration::try_from_secs_f64(secs).with_context(|| format!("duration {} is too long", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_units() -> Result<()> {
+        assert_eq!(parse("1s")?, Duration::from_secs(1));
+        assert_eq!(parse("250ms")?, Duration::from_millis(250));
+        assert_eq!(parse("10us")?, Duration::from_micros(10));
+        assert_eq!(parse("1.5µs")?, Duration::from_nanos(1500));
+        assert_eq!(parse("20ns")?, Duration::from_nanos(20));
+        assert!(parse("10").is_err());
+        assert!(parse("10h").is_err());
+        assert!(parse("1.2.3s").is_err());
+        assert!(parse("100000000000000000000000s").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn format_units() {
+        assert_eq!(format(Duration::from_millis(1500)), "1.50s");
+        assert_eq!(format(Duration::from_micros(2500)), "2.50ms");
+        assert_eq!(format(Duration::from_nanos(1500)), "1.5µs");
+    }
+}
@@ -0,0 +1,365 @@
+use answers::{Answers, Verdict};
+use anyhow::{bail, Context, Result};
+use aoc::{output, Format, ParseError, Part, Puzzle, Solution};
+use day02::Day02;
+use std::{env, time::Duration};
+use table::Table;
+
+mod answers;
+mod days;
+mod duration;
+mod table;
+
+const USAGE: &str = "\
+usage: aoc list
+       aoc run <day> [--part 1|2] [--input <path|->] [--time] [--budget <duration>]
+       aoc run --all [--part 1|2] [--time] [--budget <duration>]
+       aoc run [<day>|--all] --verify [--answers <path>]
+       aoc run [<day>|--all] --format json
+       aoc run 2 --model <name> [--input <path|->]";
+
+const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+enum Command {
+    List,
+    Run(RunArgs),
+    Help,
+}
+
+struct RunArgs {
+    days: Vec<Solution>,
+    part: Option<Part>,
+    input: Option<String>,
+    /// Print how long each day took, warning about parts over `budget`.
+    time: bool,
+    budget: Duration,
+    /// Check answers against known ones instead of printing them.
+    answers: Option<Answers>,
+    format: Format,
+    /// Run day 2's commands with this `SubmarineModel` instead of solving its parts.
+    model: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command> {
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Ok(Command::Help),
+    };
+
+    match command.as_str() {
+        "list" => Ok(Command::List),
+        "run" => parse_run_args(args).map(Command::Run),
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        _ => bail!("unknown command {}\n{}", command, USAGE),
+    }
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut time = false;
+    let mut budget = DEFAULT_BUDGET;
+    let mut verify = false;
+    let mut answers_path = None;
+    let mut format = Format::Text;
+    let mut model = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" | "-a" => all = true,
+            "--part" | "-p" => part = Some(args.next().context("--part requires 1 or 2")?.parse()?),
+            "--input" | "-i" => input = Some(args.next().context("--input requires a path")?),
+            "--time" | "-t" => time = true,
+            "--budget" => {
+                budget = duration::parse(&args.next().context("--budget requires a duration")?)?;
+                time = true;
+            }
+            "--verify" | "-v" => verify = true,
+            "--answers" => {
+                answers_path = Some(args.next().context("--answers requires a path")?);
+                verify = true;
+            }
+            "--format" | "-f" => format = args.next().context("--format requires text or json")?.parse()?,
+            "--model" | "-m" => model = Some(args.next().context("--model requires a model name")?),
+            _ if day.is_none() && !arg.starts_with('-') => {
+                day = Some(arg.parse::<u8>().with_context(|| format!("invalid day {}", arg))?);
+            }
+            _ => bail!("unexpected argument {}\n{}", arg, USAGE),
+        }
+    }
+
+    let days = match day {
+        Some(_) if all => bail!("cannot combine a day with --all"),
+        Some(day) => vec![days::find(day)?],
+        // Verifying checks every day unless told otherwise.
+        None if all || verify => days::all(),
+        None => bail!("expected a day or --all\n{}", USAGE),
+    };
+
+    if input.is_some() && days.len() > 1 {
+        bail!("--input can only be used when running a single day");
+    }
+
+    if let Some(name) = &model {
+        if days.len() > 1 || days[0].day != Day02::DAY {
+            bail!("--model only applies to day 2");
+        }
+        if part.is_some() || time || verify || format == Format::Json {
+            bail!("--model cannot be combined with --part, --time, --verify or --format json");
+        }
+        // Check the name up front, like every other argument.
+        day02::model::<isize>(name)?;
+    }
+
+    let answers = match verify {
+        true => Some(Answers::load(answers_path.as_deref().unwrap_or(answers::DEFAULT_PATH))?),
+        false => None,
+    };
+
+    Ok(RunArgs { days, part, input, time, budget, answers, format, model })
+}
+
+fn list() {
+    let mut table = Table::new(&["Day", "Title"]);
+    for solution in days::all() {
+        table.push(vec![solution.day.to_string(), solution.title.to_owned()]);
+    }
+    print!("{}", table);
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    if let Some(name) = &args.model {
+        return run_model(&args, name);
+    }
+    if args.format == Format::Json {
+        return run_json(&args, &parts);
+    }
+
+    let mut table = Table::new(&["Day", "Title", "Part 1", "Part 2"]);
+    let mut timings = Table::new(&["Day", "Title", "Parse", "Part 1", "Part 2", "Total"]);
+    let mut total = Duration::ZERO;
+    let mut pictures = Vec::new();
+    let mut warnings = Vec::new();
+    let mut errors = Vec::new();
+    let mut failures = 0;
+
+    for solution in &args.days {
+        let mut row = vec![solution.day.to_string(), solution.title.to_owned(), "-".to_owned(), "-".to_owned()];
+
+        let path = args.input.as_deref().unwrap_or(solution.input_path);
+        let report = aoc::read_input(path).and_then(|input| solution.solve(&input, &parts));
+
+        match report {
+            Ok(report) => {
+                let mut timing = vec![
+                    solution.day.to_string(),
+                    solution.title.to_owned(),
+                    duration::format(report.parse_time),
+                    "-".to_owned(),
+                    "-".to_owned(),
+                    duration::format(report.total_time()),
+                ];
+                total += report.total_time();
+
+                for answer in report.answers {
+                    let part = answer.part;
+                    // The timing table has an extra parse column before the parts.
+                    timing[answer_column(part) + 1] = duration::format(answer.elapsed);
+                    if answer.elapsed > args.budget {
+                        warnings.push(format!(
+                            "day {} part {} took {}, over the {} budget",
+                            solution.day,
+                            part,
+                            duration::format(answer.elapsed),
+                            duration::format(args.budget),
+                        ));
+                    }
+
+                    row[answer_column(part)] = match (answer.result, &args.answers) {
+                        (Ok(text), Some(answers)) => match answers.check(solution.day, part, &text) {
+                            Verdict::Pass => "PASS".to_owned(),
+                            Verdict::Unknown => "UNKNOWN".to_owned(),
+                            Verdict::Fail { expected } => {
+                                errors.push(mismatch(solution.day, part, &expected, &text));
+                                failures += 1;
+                                "FAIL".to_owned()
+                            }
+                        },
+                        // Multi-line answers don't fit in the table, print them underneath.
+                        (Ok(text), None) if text.contains('\n') => {
+                            pictures.push((solution.day, part, text));
+                            "see below".to_owned()
+                        }
+                        (Ok(text), None) => text,
+                        (Err(e), _) => {
+                            errors.push(format!("error: day {} part {}: {:#}", solution.day, part, e));
+                            failures += 1;
+                            failed_cell(&args)
+                        }
+                    };
+                }
+
+                timings.push(timing);
+            }
+            Err(e) => {
+                // Parse errors point into the input file, which already names the day.
+                errors.push(match e.downcast_ref::<ParseError>() {
+                    Some(_) => aoc::report(&e, path),
+                    None => format!("error: day {}: {:#}", solution.day, e),
+                });
+                for part in &parts {
+                    row[answer_column(*part)] = failed_cell(&args);
+                    failures += 1;
+                }
+            }
+        }
+
+        table.push(row);
+    }
+
+    print!("{}", table);
+
+    for (day, part, picture) in pictures {
+        println!("\nday {} part {}:\n{}", day, part, picture);
+    }
+
+    if args.time {
+        let mut total_row = vec![String::new(); 6];
+        total_row[1] = "Total".to_owned();
+        total_row[5] = duration::format(total);
+        timings.push(total_row);
+        print!("\n{}", timings);
+
+        if !warnings.is_empty() {
+            eprintln!();
+            for warning in &warnings {
+                eprintln!("warning: {}", warning);
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        eprintln!();
+        for error in &errors {
+            eprintln!("{}", error);
+        }
+        bail!("{} part(s) failed", failures);
+    }
+
+    Ok(())
+}
+
+/// Prints a JSON record for every part. Every record carries its own timing,
+/// and a wrong answer when verifying is reported as that record's error.
+fn run_json(args: &RunArgs, parts: &[Part]) -> Result<()> {
+    let mut records = Vec::new();
+
+    for solution in &args.days {
+        let path = args.input.as_deref().unwrap_or(solution.input_path);
+        let report = aoc::read_input(path).and_then(|input| solution.solve(&input, parts));
+
+        let mut day_records = output::records(solution.day, &report, parts);
+        if let (Some(answers), Ok(report)) = (&args.answers, &report) {
+            for (record, answer) in day_records.iter_mut().zip(&report.answers) {
+                if let Ok(text) = &answer.result {
+                    if let Verdict::Fail { expected } = answers.check(solution.day, answer.part, text) {
+                        record.error = Some(format!("expected {}", expected.trim_end()));
+                    }
+                }
+            }
+        }
+        records.extend(day_records);
+    }
+
+    println!("{}", output::to_json(&records));
+
+    let failures = records.iter().filter(|r| r.error.is_some()).count();
+    if failures > 0 {
+        bail!("{} part(s) failed", failures);
+    }
+    Ok(())
+}
+
+/// Navigates with day 2's commands using the `SubmarineModel` called `name`
+/// and prints the product of where it ends up.
+fn run_model(args: &RunArgs, name: &str) -> Result<()> {
+    let path = args.input.as_deref().unwrap_or(day02::INPUT_PATH);
+    let commands = match aoc::read_input(path).and_then(|input| Day02::parse(&input)) {
+        Ok(commands) => commands,
+        Err(e) if e.is::<ParseError>() => {
+            eprintln!("{}", aoc::report(&e, path));
+            bail!("day 2 failed to parse");
+        }
+        Err(e) => return Err(e),
+    };
+
+    let mut model = day02::model::<isize>(name)?;
+    let answer = day02::product(day02::navigate(model.as_mut(), &commands)?)?;
+
+    let mut table = Table::new(&["Day", "Title", "Model", "Answer"]);
+    table.push(vec![Day02::DAY.to_string(), Day02::TITLE.to_owned(), name.to_owned(), answer.to_string()]);
+    print!("{}", table);
+    Ok(())
+}
+
+fn failed_cell(args: &RunArgs) -> String {
+    match args.answers {
+        Some(_) => "FAIL".to_owned(),
+        None => "error".to_owned(),
+    }
+}
+
+fn mismatch(day: u8, part: Part, expected: &str, actual: &str) -> String {
+    if expected.contains('\n') || actual.contains('\n') {
+        format!("error: day {} part {}: expected\n{}\ngot\n{}", day, part, expected.trim_end(), actual)
+    } else {
+        format!("error: day {} part {}: expected {}, got {}", day, part, expected, actual)
+    }
+}
+
+fn answer_column(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 3,
+    }
+}
+
+fn main() -> Result<()> {
+    match parse_args(env::args().skip(1))? {
+        Command::List => list(),
+        Command::Run(args) => run(args)?,
+        Command::Help => {
+            println!("{}\n\nday 2 models:", USAGE);
+            for (name, description) in day02::MODELS {
+                println!("  {:8} {}", name, description);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &str) -> Result<RunArgs> {
+        parse_run_args(args.split_whitespace().map(str::to_owned))
+    }
+
+    #[test]
+    fn models_are_only_for_day_2() -> Result<()> {
+        assert_eq!(run_args("2 --model simple")?.model.as_deref(), Some("simple"));
+        assert_eq!(run_args("2 --model sideways").err().unwrap().to_string(), "unknown model sideways, expected one of simple, aimed");
+        assert_eq!(run_args("3 --model aimed").err().unwrap().to_string(), "--model only applies to day 2");
+        assert!(run_args("2 --model aimed --part 2").is_err());
+        Ok(())
+    }
+}
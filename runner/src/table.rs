@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// A plain-text table with left-aligned columns.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(headers: &[S]) -> Self {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = (0..self.headers.len()).map(|col| {
+            self.rows.iter()
+                .filter_map(|row| row.get(col))
+                .chain(Some(&self.headers[col]))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        }).collect::<Vec<_>>();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let line = row.iter().zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.headers)?;
+        write_row(f, &widths.iter().map(|w| "-".repeat(*w)).collect::<Vec<_>>())?;
+        for row in &self.rows {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}